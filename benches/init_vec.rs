@@ -0,0 +1,48 @@
+//! Compares a plain `push` loop against writing directly into the spare capacity of a `Vec`, for a
+//! range of lengths and element sizes. This is what `VEC_PUSH_THRESHOLD` in `src/guard.rs` was
+//! chosen from.
+
+use std::hint::black_box;
+use std::vec::Vec;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+#[allow(dead_code)]
+#[path = "../src/guard.rs"]
+mod guard;
+
+const LENGTHS: [usize; 8] = [1, 2, 4, 8, 16, 32, 64, 256];
+
+fn push_loop<T, F: FnMut(usize) -> T>(length: usize, mut elem: F) -> Vec<T> {
+	let mut value = Vec::with_capacity(length);
+	for i in 0..length {
+		value.push(elem(i));
+	}
+	value
+}
+
+fn raw_fill<T, F: FnMut(usize) -> T>(length: usize, mut elem: F) -> Vec<T> {
+	// SAFETY: the `Vec` is empty with capacity for `length` elements
+	match unsafe { guard::fill_vec(Vec::with_capacity(length), length, |i| Ok::<T, ()>(elem(i))) } {
+		Ok(value) => value,
+		Err(()) => unreachable!(),
+	}
+}
+
+fn bench_size<const S: usize>(c: &mut Criterion) {
+	let mut group = c.benchmark_group(format!("init_vec/{}_bytes", S));
+	
+	for &length in &LENGTHS {
+		group.bench_with_input(BenchmarkId::new("push", length), &length, |b, &length| {
+			b.iter(|| push_loop(black_box(length), |i| [i as u8; S]))
+		});
+		group.bench_with_input(BenchmarkId::new("raw", length), &length, |b, &length| {
+			b.iter(|| raw_fill(black_box(length), |i| [i as u8; S]))
+		});
+	}
+	
+	group.finish();
+}
+
+criterion_group!(benches, bench_size::<1>, bench_size::<8>, bench_size::<64>, bench_size::<512>);
+criterion_main!(benches);
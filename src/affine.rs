@@ -0,0 +1,75 @@
+#[cfg(feature = "simd")]
+use core::simd::Simd;
+
+use super::{Init, Vec};
+
+/// The number of lanes used for the SIMD fill.
+#[cfg(feature = "simd")]
+const LANES: usize = 8;
+
+/// Collections of numbers which can be initialised with an affine sequence `a * i + b`.
+///
+/// This is implemented for `Vec`s of every primitive integer and float type. With the `simd`
+/// feature enabled, the sequence is generated using `core::simd`, otherwise a scalar loop is used.
+/// The 128-bit integers have no SIMD lanes, so always use the scalar loop. Integer arithmetic wraps
+/// on overflow in both cases.
+pub trait AffineInit<T>: Sized {
+	/// Initialise a collection of length `length` where the element at index `i` is `a * i + b`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::AffineInit;
+	///
+	/// let ramp = Vec::<f32>::init_affine(4, 0.5, 1.0);
+	///
+	/// assert_eq!(ramp, vec![1.0, 1.5, 2.0, 2.5]);
+	/// ```
+	fn init_affine(length: usize, a: T, b: T) -> Self;
+}
+
+macro_rules! impl_affine_init {
+	(@impl $t:ty, |$a:ident, $i:ident, $b:ident| $scalar:expr) => {
+		impl AffineInit<$t> for Vec<$t> {
+			#[cfg(feature = "simd")]
+			fn init_affine(length: usize, $a: $t, $b: $t) -> Self {
+				let mut value = Vec::with_capacity(length);
+				let (va, vb) = (Simd::<$t, LANES>::splat($a), Simd::<$t, LANES>::splat($b));
+				let full = length - length % LANES;
+				
+				for start in (0..full).step_by(LANES) {
+					let vi = Simd::from_array(<[$t; LANES]>::init(|l| (start + l) as $t));
+					value.extend_from_slice((va * vi + vb).as_array());
+				}
+				
+				value.extend((full..length).map(|$i| { let $i = $i as $t; $scalar }));
+				value
+			}
+			
+			#[cfg(not(feature = "simd"))]
+			fn init_affine(length: usize, $a: $t, $b: $t) -> Self {
+				Vec::init_with(length, |$i| { let $i = $i as $t; $scalar })
+			}
+		}
+	};
+	(@scalar $t:ty, |$a:ident, $i:ident, $b:ident| $scalar:expr) => {
+		impl AffineInit<$t> for Vec<$t> {
+			fn init_affine(length: usize, $a: $t, $b: $t) -> Self {
+				Vec::init_with(length, |$i| { let $i = $i as $t; $scalar })
+			}
+		}
+	};
+	(wide_integers: $($t:ty),* $(,)?) => {
+		$(impl_affine_init!(@scalar $t, |a, i, b| a.wrapping_mul(i).wrapping_add(b));)*
+	};
+	(integers: $($t:ty),* $(,)?) => {
+		$(impl_affine_init!(@impl $t, |a, i, b| a.wrapping_mul(i).wrapping_add(b));)*
+	};
+	(floats: $($t:ty),* $(,)?) => {
+		$(impl_affine_init!(@impl $t, |a, i, b| a * i + b);)*
+	};
+}
+
+impl_affine_init!(integers: u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_affine_init!(wide_integers: u128, i128);
+impl_affine_init!(floats: f32, f64);
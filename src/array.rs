@@ -0,0 +1,804 @@
+#[cfg(any(feature = "std", feature = "alloc"))]
+use core::convert::TryInto;
+use core::fmt;
+use core::mem::{MaybeUninit, forget};
+use core::ops::ControlFlow;
+
+use super::{Init, TryInit};
+use super::guard::{PartialInit, init_ordered};
+use super::strategy::InitStrategy;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use super::Vec;
+
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::{collections::HashMap, env, format};
+
+/// An error from building an array out of a collection with the wrong number of elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LengthError {
+	/// The length of the array.
+	pub expected: usize,
+	/// The length of the collection.
+	pub actual: usize,
+}
+
+impl fmt::Display for LengthError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "expected {} elements but found {}", self.expected, self.actual)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthError { }
+
+/// Additional initialisers for arrays which don't fit the general `Init` interface.
+pub trait ArrayInit<T, const N: usize>: Init<T, usize> {
+	/// Initialise an array by applying `elem` to each index, checking that `elem` is pure.
+	///
+	/// With the `debug_checks` feature enabled in a debug build, `elem` is called a second time
+	/// for one index and the two results are asserted to be equal, catching closures which
+	/// accidentally depend on state. Otherwise this is the same as `init`.
+	///
+	/// The `T: PartialEq` bound is required in every build, even when the check is off, so that
+	/// enabling `debug_checks` can never stop a caller from compiling.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let arr = <[usize; 5]>::from_fn_exact(|i| i * i);
+	///
+	/// assert_eq!(arr, [0, 1, 4, 9, 16]);
+	/// ```
+	fn from_fn_exact<F: FnMut(usize) -> T>(elem: F) -> Self where T: PartialEq;
+	
+	/// Initialise an array by applying `elem` to each index, using `n_threads` threads which each
+	/// fill a contiguous stripe of the array.
+	///
+	/// A `n_threads` of `0` is treated as `1`. If `elem` panics, the elements built so far on all
+	/// threads are dropped and the panic is propagated once every thread has finished.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let arr = <[usize; 8]>::init_striped_with(3, |i| i * 2);
+	///
+	/// assert_eq!(arr, [0, 2, 4, 6, 8, 10, 12, 14]);
+	/// ```
+	#[cfg(feature = "std")]
+	fn init_striped_with<F: Fn(usize) -> T + Sync>(n_threads: usize, elem: F) -> Self where T: Send;
+	
+	/// Partially initialise an array by applying `elem` to each index until it returns `None`,
+	/// returning the number of elements initialised along with the buffer.
+	///
+	/// Only the first `len` elements of the buffer are initialised, and the caller is responsible
+	/// for dropping them. If `elem` panics, the elements initialised so far are dropped.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let input = [1, 2, 3];
+	/// let (len, buf) = <[u8; 8]>::init_partial(|i| input.get(i).copied());
+	///
+	/// assert_eq!(len, 3);
+	/// assert_eq!(unsafe { buf[2].assume_init() }, 3);
+	/// ```
+	fn init_partial<F: FnMut(usize) -> Option<T>>(elem: F) -> (usize, [MaybeUninit<T>; N]);
+	
+	/// Initialise an array by consuming `src` and applying `elem` to each index and the element of
+	/// `src` at that index.
+	///
+	/// Returns an error without calling `elem` if `src` doesn't have exactly `N` elements.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::{ArrayInit, LengthError};
+	///
+	/// let arr = <[String; 3]>::init_map_vec(vec!['a', 'b', 'c'], |i, c| format!("{}{}", i, c));
+	/// assert_eq!(arr, Ok(["0a".to_string(), "1b".to_string(), "2c".to_string()]));
+	///
+	/// let err = <[String; 3]>::init_map_vec(vec!['a'], |i, c| format!("{}{}", i, c));
+	/// assert_eq!(err, Err(LengthError { expected: 3, actual: 1 }));
+	/// ```
+	#[cfg(any(feature = "std", feature = "alloc"))]
+	fn init_map_vec<S, F: FnMut(usize, S) -> T>(src: Vec<S>, elem: F) -> Result<Self, LengthError>;
+	
+	/// Initialise an array by building a `Vec` of length `length` with `elem`, then converting it
+	/// to an array, or returning the `Vec` as an error if `length` isn't `N`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// assert_eq!(<[usize; 3]>::init_to_array(3, |i| i), Ok([0, 1, 2]));
+	/// assert_eq!(<[usize; 3]>::init_to_array(2, |i| i), Err(vec![0, 1]));
+	/// ```
+	#[cfg(any(feature = "std", feature = "alloc"))]
+	fn init_to_array<F: FnMut(usize) -> T>(length: usize, elem: F) -> Result<Self, Vec<T>>;
+	
+	/// Initialise an array by applying `elem` to each index `i`, storing the result at position
+	/// `perm(i)` rather than `i`.
+	///
+	/// `elem` is still called in index order. `perm` is called once per index, before `elem`.
+	///
+	/// # Panics
+	///
+	/// Panics if `perm` isn't a permutation of `0..N`, i.e. if it returns an out of range position or
+	/// the same position twice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let reversed = <[usize; 4]>::init_permuted_with(|i| 3 - i, |i| i * 10);
+	///
+	/// assert_eq!(reversed, [30, 20, 10, 0]);
+	/// ```
+	fn init_permuted_with<P: Fn(usize) -> usize, F: FnMut(usize) -> T>(perm: P, elem: F) -> Self;
+	
+	/// Initialise an array by applying `elem` to each index, stopping early if it returns
+	/// `ControlFlow::Break`.
+	///
+	/// If any index breaks, the elements built so far are dropped and the break value is returned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use std::ops::ControlFlow;
+	/// use init_trait::ArrayInit;
+	///
+	/// let lines = ["1", "2", "end", "4"];
+	/// let arr = <[u32; 4]>::init_control_with(|i| match lines[i].parse() {
+	///     Ok(x) => ControlFlow::Continue(x),
+	///     Err(_) => ControlFlow::Break(i),
+	/// });
+	///
+	/// assert_eq!(arr, ControlFlow::Break(2));
+	/// ```
+	fn init_control_with<B, F: FnMut(usize) -> ControlFlow<B, T>>(elem: F) -> ControlFlow<B, Self>;
+	
+	/// Initialise an array by cloning the elements of `src`, then applying `pad` to each remaining
+	/// index if `src` is shorter than the array.
+	///
+	/// If `src` is longer than the array, the extra elements are ignored.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let arr = <[u8; 5]>::init_from_slice_padded(&[1, 2, 3], |_| 0);
+	///
+	/// assert_eq!(arr, [1, 2, 3, 0, 0]);
+	/// ```
+	fn init_from_slice_padded<F: FnMut(usize) -> T>(src: &[T], pad: F) -> Self where T: Clone;
+	
+	/// Initialise an array by applying `elem` to each index which is a multiple of `stride`, and
+	/// cloning `fill` into every other position.
+	///
+	/// # Panics
+	///
+	/// Panics if `stride` is `0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let seeded = <[u32; 7]>::init_strided_with(3, 0, |i| i as u32 + 1);
+	///
+	/// assert_eq!(seeded, [1, 0, 0, 4, 0, 0, 7]);
+	/// ```
+	fn init_strided_with<F: FnMut(usize) -> T>(stride: usize, fill: T, elem: F) -> Self where T: Clone;
+	
+	/// Initialise an array by taking elements from `iter` until it runs out, then applying `elem`
+	/// to each remaining index.
+	///
+	/// `iter` is never advanced past the end of the array.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let arr = <[usize; 5]>::init_iter_then(vec![7, 8].into_iter(), |i| i * 100);
+	///
+	/// assert_eq!(arr, [7, 8, 200, 300, 400]);
+	/// ```
+	fn init_iter_then<It: Iterator<Item = T>, F: FnMut(usize) -> T>(iter: It, elem: F) -> Self;
+	
+	/// Initialise an array by applying `elem` to each index, calling `observe` with each element
+	/// right after it's produced and before it's stored.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let mut max = 0;
+	/// let arr = <[u32; 4]>::init_tee_with(|i| [3, 9, 2, 5][i], |_, &x| max = max.max(x));
+	///
+	/// assert_eq!(arr, [3, 9, 2, 5]);
+	/// assert_eq!(max, 9);
+	/// ```
+	fn init_tee_with<F: FnMut(usize) -> T, O: FnMut(usize, &T)>(elem: F, observe: O) -> Self;
+	
+	/// Initialise an array by applying `elem` to each index, visiting the indices in the order
+	/// chosen by the strategy `S`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::{ArrayInit, Reversed};
+	///
+	/// let mut order = Vec::new();
+	/// let arr = <[usize; 3]>::init_strategy::<Reversed, _>(|i| { order.push(i); i });
+	///
+	/// assert_eq!(arr, [0, 1, 2]);
+	/// assert_eq!(order, vec![2, 1, 0]);
+	/// ```
+	fn init_strategy<S: InitStrategy<T, F>, F>(elem: F) -> Self;
+	
+	/// Initialise an array by applying `elem` to each index shifted by `offset`, so that the element
+	/// at position `i` is `elem(offset + i)`.
+	///
+	/// This is useful when filling one tile of a larger structure, where the closure works in
+	/// global coordinates.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let tile = <[usize; 3]>::init_offset_with(100, |i| i * 2);
+	///
+	/// assert_eq!(tile, [200, 202, 204]);
+	/// ```
+	fn init_offset_with<F: FnMut(usize) -> T>(offset: usize, elem: F) -> Self;
+	
+	/// Initialise an array by applying `elem` to each index, checking that no two elements are
+	/// equal.
+	///
+	/// On a duplicate, returns the indices `(i, j)` with `i < j` of the first pair found, which is
+	/// the one with the smallest `j`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// assert_eq!(<[char; 3]>::init_unique_array_with(|i| ['a', 'b', 'c'][i]), Ok(['a', 'b', 'c']));
+	/// assert_eq!(<[char; 4]>::init_unique_array_with(|i| ['a', 'b', 'c', 'b'][i]), Err((1, 3)));
+	/// ```
+	#[cfg(feature = "std")]
+	fn init_unique_array_with<F: FnMut(usize) -> T>(elem: F) -> Result<Self, (usize, usize)> where T: Eq + Hash;
+	
+	/// Initialise an array by applying `elem` to each index along with a shared reference to `ctx`.
+	///
+	/// This lets the generator be parameterised without capturing, and `ctx` can itself be a
+	/// reference passed down from an outer initialisation.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let arr = <[usize; 4]>::init_with_context(10, |i, scale| i * scale);
+	///
+	/// assert_eq!(arr, [0, 10, 20, 30]);
+	/// ```
+	fn init_with_context<C, F: FnMut(usize, &C) -> T>(ctx: C, elem: F) -> Self;
+	
+	/// Initialise an array from the environment variables `{prefix}_0`, `{prefix}_1`, and so on,
+	/// parsing each with `parse`, and applying `fallback` to the index of any that are missing or
+	/// don't parse.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// std::env::set_var("LIMITS_1", "250");
+	///
+	/// let limits = <[u32; 3]>::init_from_env("LIMITS", |s| s.parse().ok(), |_| 100);
+	///
+	/// assert_eq!(limits, [100, 250, 100]);
+	/// ```
+	#[cfg(feature = "std")]
+	fn init_from_env<P: Fn(&str) -> Option<T>, F: FnMut(usize) -> T>(prefix: &str, parse: P, fallback: F) -> Self;
+	
+	/// Run `iters` Jacobi-style sweeps starting from `initial`, where each sweep rebuilds every
+	/// element by applying `update` to its index and the whole array from the previous sweep.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// // Each interior cell moves to the average of its neighbours
+	/// let arr = <[f64; 3]>::init_relax([0.0, 5.0, 1.0], 1, |i, prev| match i {
+	///     1 => (prev[0] + prev[2]) / 2.0,
+	///     _ => prev[i],
+	/// });
+	///
+	/// assert_eq!(arr, [0.0, 0.5, 1.0]);
+	/// ```
+	fn init_relax<F: FnMut(usize, &[T]) -> T>(initial: Self, iters: usize, update: F) -> Self;
+	
+	/// Initialise an array by applying `first` to each index below `split` and `second` to the
+	/// rest, such as for a warm-up phase followed by a steady state.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let arr = <[u32; 5]>::init_phased_with(2, |_| 0, |i| i as u32);
+	///
+	/// assert_eq!(arr, [0, 0, 2, 3, 4]);
+	/// ```
+	fn init_phased_with<F: FnMut(usize) -> T, G: FnMut(usize) -> T>(split: usize, first: F, second: G) -> Self;
+	
+	/// Initialise an array by applying `elem` to each index, where the elements should come out in
+	/// non-decreasing order.
+	///
+	/// In debug builds this panics with the index of the first element which is less than (or
+	/// incomparable with) the one before it. Otherwise this is the same as `init`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let keys = <[u32; 4]>::init_monotonic_with(|i| (i * i) as u32);
+	///
+	/// assert_eq!(keys, [0, 1, 4, 9]);
+	/// ```
+	fn init_monotonic_with<F: FnMut(usize) -> T>(elem: F) -> Self where T: PartialOrd;
+	
+	/// Initialise an array by applying `elem` to each index, also returning the smallest and
+	/// largest elements, or `None` if the array is empty.
+	///
+	/// Ties keep the earliest element, and elements which are incomparable with the current
+	/// extremes (such as NaN) are never picked.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let (arr, extents) = <[i32; 4]>::init_with_extents(|i| [3, -1, 7, 2][i]);
+	///
+	/// assert_eq!(arr, [3, -1, 7, 2]);
+	/// assert_eq!(extents, Some((-1, 7)));
+	/// ```
+	fn init_with_extents<F: FnMut(usize) -> T>(elem: F) -> (Self, Option<(T, T)>) where T: Clone + PartialOrd;
+	
+	/// Initialise an array of `B.pow(D)` elements by applying `elem` to the `D` base-`B` digits of
+	/// each index, most significant first.
+	///
+	/// Using this with an `N` other than `B.pow(D)` fails to compile.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let names = <[String; 4]>::init_radix_digits_with::<2, 2, _>(|[a, b]| format!("{}{}", a, b));
+	///
+	/// assert_eq!(names, ["00", "01", "10", "11"]);
+	/// ```
+	fn init_radix_digits_with<const B: usize, const D: usize, F: FnMut([usize; D]) -> T>(elem: F) -> Self;
+	
+	/// Initialise an array by applying `special` to every index which is a multiple of `period`,
+	/// starting from `0`, and `regular` to the rest.
+	///
+	/// # Panics
+	///
+	/// Panics if `period` is `0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::ArrayInit;
+	///
+	/// let ruler = <[char; 7]>::init_periodic_with(3, |_| '-', |_| '|');
+	///
+	/// assert_eq!(ruler, ['|', '-', '-', '|', '-', '-', '|']);
+	/// ```
+	fn init_periodic_with<F: FnMut(usize) -> T, G: FnMut(usize) -> T>(period: usize, regular: F, special: G) -> Self;
+}
+
+impl<T, const N: usize> ArrayInit<T, N> for [T; N] {
+	#[cfg_attr(not(all(feature = "debug_checks", debug_assertions)), allow(unused_mut))]
+	fn from_fn_exact<F: FnMut(usize) -> T>(mut elem: F) -> Self where T: PartialEq {
+		let res = Self::init(&mut elem);
+		
+		#[cfg(all(feature = "debug_checks", debug_assertions))]
+		if N > 0 {
+			let i = debug_checks::random_index(N);
+			assert!(elem(i) == res[i], "init function returned a different value when called again for index {}", i);
+		}
+		
+		res
+	}
+	
+	#[cfg(feature = "std")]
+	fn init_striped_with<F: Fn(usize) -> T + Sync>(n_threads: usize, elem: F) -> Self where T: Send {
+		let mut value = MaybeUninit::<Self>::uninit();
+		
+		// SAFETY: `fill_striped` initialises all `N` elements or panics
+		unsafe {
+			super::thread::fill_striped(value.as_mut_ptr() as *mut T, N, n_threads, &elem);
+			value.assume_init()
+		}
+	}
+	
+	fn init_partial<F: FnMut(usize) -> Option<T>>(mut elem: F) -> (usize, [MaybeUninit<T>; N]) {
+		// SAFETY: an array of `MaybeUninit` doesn't need initialising
+		let mut buf: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+		let mut guard = PartialInit { ptr: buf.as_mut_ptr() as *mut T, done: 0, order: |i| i };
+		
+		while guard.done < N {
+			match elem(guard.done) {
+				// SAFETY: `done < N`, so this is within the buffer
+				Some(x) => unsafe { guard.ptr.add(guard.done).write(x) },
+				None => break,
+			}
+			guard.done += 1;
+		}
+		
+		let len = guard.done;
+		forget(guard);
+		(len, buf)
+	}
+	
+	#[cfg(any(feature = "std", feature = "alloc"))]
+	fn init_map_vec<S, F: FnMut(usize, S) -> T>(src: Vec<S>, mut elem: F) -> Result<Self, LengthError> {
+		if src.len() != N {
+			return Err(LengthError { expected: N, actual: src.len() });
+		}
+		
+		let mut src = src.into_iter();
+		Ok(Self::init(|i| elem(i, src.next().unwrap())))
+	}
+	
+	#[cfg(any(feature = "std", feature = "alloc"))]
+	fn init_to_array<F: FnMut(usize) -> T>(length: usize, elem: F) -> Result<Self, Vec<T>> {
+		Vec::init_with(length, elem).try_into()
+	}
+	
+	fn init_permuted_with<P: Fn(usize) -> usize, F: FnMut(usize) -> T>(perm: P, mut elem: F) -> Self {
+		// Record the positions first, so the writes don't depend on `perm` giving the same answers twice
+		let positions = <[usize; N]>::init(perm);
+		let mut seen = [false; N];
+		
+		for (i, &p) in positions.iter().enumerate() {
+			assert!(p < N, "permutation maps index {} to {}, which is out of range for length {}", i, p, N);
+			assert!(!seen[p], "permutation maps index {} to {}, which is already used", i, p);
+			seen[p] = true;
+		}
+		
+		let mut i = 0;
+		// SAFETY: `positions` has been checked to be a permutation of `0..N`
+		unsafe {
+			init_ordered(N, |k| positions[k], |_| {
+				let x = elem(i);
+				i += 1;
+				x
+			})
+		}
+	}
+	
+	fn init_control_with<B, F: FnMut(usize) -> ControlFlow<B, T>>(mut elem: F) -> ControlFlow<B, Self> {
+		let value = Self::try_init(|i| match elem(i) {
+			ControlFlow::Continue(x) => Ok(x),
+			ControlFlow::Break(b) => Err(b),
+		});
+		
+		match value {
+			Ok(value) => ControlFlow::Continue(value),
+			Err(b) => ControlFlow::Break(b),
+		}
+	}
+	
+	fn init_from_slice_padded<F: FnMut(usize) -> T>(src: &[T], mut pad: F) -> Self where T: Clone {
+		Self::init(|i| match src.get(i) {
+			Some(x) => x.clone(),
+			None => pad(i),
+		})
+	}
+	
+	fn init_strided_with<F: FnMut(usize) -> T>(stride: usize, fill: T, mut elem: F) -> Self where T: Clone {
+		assert!(stride > 0, "stride must be greater than 0");
+		
+		Self::init(|i| if i % stride == 0 { elem(i) } else { fill.clone() })
+	}
+	
+	fn init_iter_then<It: Iterator<Item = T>, F: FnMut(usize) -> T>(iter: It, mut elem: F) -> Self {
+		let mut iter = iter.fuse();
+		
+		Self::init(|i| iter.next().unwrap_or_else(|| elem(i)))
+	}
+	
+	fn init_tee_with<F: FnMut(usize) -> T, O: FnMut(usize, &T)>(mut elem: F, mut observe: O) -> Self {
+		Self::init(|i| {
+			let x = elem(i);
+			observe(i, &x);
+			x
+		})
+	}
+	
+	fn init_strategy<S: InitStrategy<T, F>, F>(elem: F) -> Self {
+		S::init_array(elem)
+	}
+	
+	fn init_offset_with<F: FnMut(usize) -> T>(offset: usize, mut elem: F) -> Self {
+		Self::init(|i| elem(offset + i))
+	}
+	
+	#[cfg(feature = "std")]
+	fn init_unique_array_with<F: FnMut(usize) -> T>(elem: F) -> Result<Self, (usize, usize)> where T: Eq + Hash {
+		let value = Self::init(elem);
+		let mut first = HashMap::with_capacity(N);
+		
+		for (j, x) in value.iter().enumerate() {
+			if let Some(&i) = first.get(x) {
+				return Err((i, j));
+			}
+			first.insert(x, j);
+		}
+		
+		Ok(value)
+	}
+	
+	fn init_with_context<C, F: FnMut(usize, &C) -> T>(ctx: C, mut elem: F) -> Self {
+		Self::init(|i| elem(i, &ctx))
+	}
+	
+	#[cfg(feature = "std")]
+	fn init_from_env<P: Fn(&str) -> Option<T>, F: FnMut(usize) -> T>(prefix: &str, parse: P, mut fallback: F) -> Self {
+		Self::init(|i| {
+			env::var(format!("{}_{}", prefix, i)).ok()
+				.and_then(|s| parse(&s))
+				.unwrap_or_else(|| fallback(i))
+		})
+	}
+	
+	fn init_relax<F: FnMut(usize, &[T]) -> T>(initial: Self, iters: usize, mut update: F) -> Self {
+		let mut value = initial;
+		
+		for _ in 0..iters {
+			value = Self::init(|i| update(i, &value));
+		}
+		
+		value
+	}
+	
+	fn init_phased_with<F: FnMut(usize) -> T, G: FnMut(usize) -> T>(split: usize, mut first: F, mut second: G) -> Self {
+		Self::init(|i| if i < split { first(i) } else { second(i) })
+	}
+	
+	fn init_monotonic_with<F: FnMut(usize) -> T>(elem: F) -> Self where T: PartialOrd {
+		let value = Self::init(elem);
+		
+		#[cfg(debug_assertions)]
+		for (i, pair) in value.windows(2).enumerate() {
+			assert!(pair[1] >= pair[0], "element at index {} is less than the element before it", i + 1);
+		}
+		
+		value
+	}
+	
+	fn init_with_extents<F: FnMut(usize) -> T>(mut elem: F) -> (Self, Option<(T, T)>) where T: Clone + PartialOrd {
+		let mut extents: Option<(T, T)> = None;
+		
+		let value = Self::init(|i| {
+			let x = elem(i);
+			match &mut extents {
+				None => extents = Some((x.clone(), x.clone())),
+				Some((min, max)) => {
+					if x < *min { *min = x.clone(); }
+					if x > *max { *max = x.clone(); }
+				},
+			}
+			x
+		});
+		
+		(value, extents)
+	}
+	
+	fn init_radix_digits_with<const B: usize, const D: usize, F: FnMut([usize; D]) -> T>(mut elem: F) -> Self {
+		const { assert!(N == B.pow(D as u32), "the array length must be B.pow(D)") };
+		
+		Self::init(|i| {
+			let mut rest = i;
+			let mut digits = [0; D];
+			for d in digits.iter_mut().rev() {
+				*d = rest % B;
+				rest /= B;
+			}
+			elem(digits)
+		})
+	}
+	
+	fn init_periodic_with<F: FnMut(usize) -> T, G: FnMut(usize) -> T>(period: usize, mut regular: F, mut special: G) -> Self {
+		assert!(period > 0, "period must be greater than 0");
+		
+		Self::init(|i| if i % period == 0 { special(i) } else { regular(i) })
+	}
+}
+
+#[cfg(all(feature = "debug_checks", debug_assertions))]
+mod debug_checks {
+	use core::sync::atomic::{AtomicUsize, Ordering};
+	
+	use super::super::guard::splitmix64;
+	
+	static CALLS: AtomicUsize = AtomicUsize::new(0);
+	
+	/// Pick a pseudo-random index less than `n`, varying between calls.
+	pub fn random_index(n: usize) -> usize {
+		let mut state = CALLS.fetch_add(1, Ordering::Relaxed) as u64;
+		(splitmix64(&mut state) % n as u64) as usize
+	}
+}
+
+/// Initialise an array of optional slots by applying `elem` to each index, where `None` marks an
+/// empty slot.
+///
+/// This is the same as `init`, but pairs with `count_filled` for partially populated arrays.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::{init_options_with, count_filled};
+///
+/// let slots: [Option<usize>; 4] = init_options_with(|i| if i % 2 == 0 { Some(i) } else { None });
+///
+/// assert_eq!(slots, [Some(0), None, Some(2), None]);
+/// assert_eq!(count_filled(&slots), 2);
+/// ```
+pub fn init_options_with<T, F: FnMut(usize) -> Option<T>, const N: usize>(elem: F) -> [Option<T>; N] {
+	<[Option<T>; N]>::init(elem)
+}
+
+/// Count how many slots are filled (`Some`).
+pub fn count_filled<T>(slots: &[Option<T>]) -> usize {
+	slots.iter().filter(|slot| slot.is_some()).count()
+}
+
+/// Initialise a boolean mask by applying the predicate `elem` to each index.
+///
+/// This is the same as `init`, but pairs with `count_set` for sieves and other masks.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::{init_mask, count_set};
+///
+/// let even: [bool; 5] = init_mask(|i| i % 2 == 0);
+///
+/// assert_eq!(even, [true, false, true, false, true]);
+/// assert_eq!(count_set(&even), 3);
+/// ```
+pub fn init_mask<F: FnMut(usize) -> bool, const N: usize>(elem: F) -> [bool; N] {
+	<[bool; N]>::init(elem)
+}
+
+/// Count how many entries of a mask are set (`true`).
+pub fn count_set(mask: &[bool]) -> usize {
+	mask.iter().filter(|&&set| set).count()
+}
+
+/// Create an array of uninitialised `T`s, for filling through a raw pointer, such as an FFI output
+/// buffer.
+///
+/// Pair this with `assume_init_array` once every element has been written.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::{uninit_array, assume_init_array};
+///
+/// let mut buf = uninit_array::<u16, 4>();
+/// let ptr = buf.as_mut_ptr() as *mut u16;
+///
+/// for i in 0..4 {
+///     // SAFETY: `i < 4`, so this is within the buffer
+///     unsafe { ptr.add(i).write(i as u16 * 3) };
+/// }
+///
+/// // SAFETY: all 4 elements were written above
+/// assert_eq!(unsafe { assume_init_array(buf) }, [0, 3, 6, 9]);
+/// ```
+pub fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
+	// SAFETY: an array of `MaybeUninit` doesn't need initialising
+	unsafe { MaybeUninit::uninit().assume_init() }
+}
+
+/// Convert an array of `MaybeUninit<T>`s into an array of `T`s.
+///
+/// # Safety
+///
+/// Every element of `arr` must be fully initialised.
+pub unsafe fn assume_init_array<T, const N: usize>(arr: [MaybeUninit<T>; N]) -> [T; N] {
+	// `[MaybeUninit<T>; N]` has the same layout as `[T; N]` and doesn't drop its elements
+	(&arr as *const [MaybeUninit<T>; N] as *const [T; N]).read()
+}
+
+/// Reorder `source` by the permutation `perm`, so that element `i` of the result is
+/// `source[perm[i]]`.
+///
+/// This is a `const fn`, so constant tables such as S-box layouts can be permuted at compile time.
+///
+/// `perm` is an ordinary argument rather than a `const PERM: [usize; N]` parameter, since array
+/// const parameters need the unstable `adt_const_params` feature. This means it is only checked at
+/// compile time when the result is a constant.
+///
+/// # Panics
+///
+/// Panics (or fails to compile, in a constant) if `perm` isn't a permutation of `0..N`.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_const_permuted;
+///
+/// const SBOX: [u8; 4] = init_const_permuted([10, 20, 30, 40], [2, 0, 3, 1]);
+///
+/// assert_eq!(SBOX, [30, 10, 40, 20]);
+/// ```
+pub const fn init_const_permuted<T: Copy, const N: usize>(source: [T; N], perm: [usize; N]) -> [T; N] {
+	let mut seen = [false; N];
+	let mut value = source;
+	
+	let mut i = 0;
+	while i < N {
+		let p = perm[i];
+		assert!(p < N, "permutation index out of range");
+		assert!(!seen[p], "permutation index repeated");
+		seen[p] = true;
+		value[i] = source[p];
+		i += 1;
+	}
+	
+	value
+}
+
+/// Copy `src` into an array of the same length.
+///
+/// This is a `const fn`, so constant arrays can be taken from constant slices, and a length
+/// mismatch in a constant is a compile error.
+///
+/// # Panics
+///
+/// Panics if `src.len() != N`.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_from_const_slice;
+///
+/// const PORTS: &[u16] = &[80, 443, 8080];
+/// const FIRST_TWO: [u16; 2] = init_from_const_slice(PORTS.split_at(2).0);
+///
+/// assert_eq!(FIRST_TWO, [80, 443]);
+/// ```
+pub const fn init_from_const_slice<T: Copy, const N: usize>(src: &[T]) -> [T; N] {
+	assert!(src.len() == N, "slice length doesn't match the array length");
+	
+	// SAFETY: `src` has exactly `N` elements, laid out like `[T; N]`, and `T: Copy`
+	unsafe { *(src.as_ptr() as *const [T; N]) }
+}
@@ -0,0 +1,172 @@
+use core::cmp::Reverse;
+
+use super::{Init, Vec};
+
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, BinaryHeap, VecDeque};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::collections::{BTreeMap, BinaryHeap, VecDeque};
+
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+impl<T> Init<T, usize, usize> for VecDeque<T> {
+	fn init_with<F: FnMut(usize) -> T>(length: usize, mut elem: F) -> Self {
+		let mut value = VecDeque::with_capacity(length);
+		
+		for i in 0..length {
+			value.push_back(elem(i));
+		}
+		
+		value
+	}
+}
+
+/// Builds a map from `length` key-value pairs. If a key is repeated, the later value replaces the
+/// earlier one, as with `insert`.
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher + Default> Init<(K, V), usize, usize> for HashMap<K, V, S> {
+	fn init_with<F: FnMut(usize) -> (K, V)>(length: usize, mut elem: F) -> Self {
+		let mut value = HashMap::with_capacity_and_hasher(length, S::default());
+		
+		for i in 0..length {
+			let (k, v) = elem(i);
+			value.insert(k, v);
+		}
+		
+		value
+	}
+}
+
+/// Initialise a `VecDeque` of length `length` by applying `elem` to each index, rotated so that
+/// the element for index `front` is at the front.
+///
+/// # Panics
+///
+/// Panics if `front > length`.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_deque_rotated_with;
+///
+/// let deque = init_deque_rotated_with(5, 2, |i| i);
+///
+/// assert_eq!(deque, [2, 3, 4, 0, 1]);
+/// ```
+pub fn init_deque_rotated_with<T, F: FnMut(usize) -> T>(length: usize, front: usize, elem: F) -> VecDeque<T> {
+	assert!(front <= length, "front index {} is out of range for length {}", front, length);
+	
+	let mut value = VecDeque::init_with(length, elem);
+	value.rotate_left(front);
+	value
+}
+
+/// Initialise a `VecDeque` by applying `elem` to each index in `0..produce`, keeping only the
+/// most recent `max` elements.
+///
+/// This models a sliding window: once the deque is full, each new element pushes the oldest one
+/// off the front. `elem` is still called for every index.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_bounded_deque_with;
+///
+/// let window = init_bounded_deque_with(10, 3, |i| i);
+///
+/// assert_eq!(window, [7, 8, 9]);
+/// ```
+pub fn init_bounded_deque_with<T, F: FnMut(usize) -> T>(produce: usize, max: usize, mut elem: F) -> VecDeque<T> {
+	let mut value = VecDeque::with_capacity(produce.min(max));
+	
+	for i in 0..produce {
+		let x = elem(i);
+		if max == 0 { continue; }
+		if value.len() == max {
+			value.pop_front();
+		}
+		value.push_back(x);
+	}
+	
+	value
+}
+
+/// Initialise a `BTreeMap` from `length` key-value pairs given by applying `elem` to each index,
+/// where the keys are strictly increasing.
+///
+/// Knowing the keys are already sorted lets the map be built in bulk rather than by repeated
+/// insertion. In debug builds this panics if the keys aren't strictly increasing.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_sorted_map;
+///
+/// let squares = init_sorted_map(4, |i| (i, i * i));
+///
+/// assert_eq!(squares[&3], 9);
+/// assert_eq!(squares.keys().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+/// ```
+pub fn init_sorted_map<K: Ord, V, F: FnMut(usize) -> (K, V)>(length: usize, elem: F) -> BTreeMap<K, V> {
+	let entries = Vec::init_with(length, elem);
+	
+	#[cfg(debug_assertions)]
+	for (i, pair) in entries.windows(2).enumerate() {
+		assert!(pair[0].0 < pair[1].0, "key at index {} isn't greater than the key before it", i + 1);
+	}
+	
+	entries.into_iter().collect()
+}
+
+/// Initialise a min-heap of `length` elements by applying `elem` to each index.
+///
+/// Each element is wrapped in `Reverse`, so popping from the heap yields the elements in ascending
+/// order.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::cmp::Reverse;
+/// use init_trait::init_min_heap;
+///
+/// let mut heap = init_min_heap(4, |i| [3, 1, 4, 1][i]);
+///
+/// assert_eq!(heap.pop(), Some(Reverse(1)));
+/// assert_eq!(heap.pop(), Some(Reverse(1)));
+/// assert_eq!(heap.pop(), Some(Reverse(3)));
+/// ```
+pub fn init_min_heap<T: Ord, F: FnMut(usize) -> T>(length: usize, mut elem: F) -> BinaryHeap<Reverse<T>> {
+	Vec::init_with(length, |i| Reverse(elem(i))).into()
+}
+
+/// Initialise a map which groups the indices `0..length` into buckets named by `key(i)`.
+///
+/// The indices in each bucket are in increasing order.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_group_by;
+///
+/// let words = ["apple", "bean", "avocado", "beet", "corn"];
+/// let by_letter = init_group_by(words.len(), |i| words[i].chars().next().unwrap());
+///
+/// assert_eq!(by_letter[&'a'], vec![0, 2]);
+/// assert_eq!(by_letter[&'b'], vec![1, 3]);
+/// assert_eq!(by_letter.len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn init_group_by<K: Eq + Hash, F: FnMut(usize) -> K>(length: usize, mut key: F) -> HashMap<K, Vec<usize>> {
+	let mut value: HashMap<K, Vec<usize>> = HashMap::new();
+	
+	for i in 0..length {
+		value.entry(key(i)).or_default().push(i);
+	}
+	
+	value
+}
@@ -0,0 +1,33 @@
+use core::ops::{Coroutine, CoroutineState};
+use core::pin::pin;
+
+use super::Vec;
+
+/// Initialise a `Vec` from the values yielded by the coroutine `gen`, resuming it until it
+/// returns.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(coroutines)]
+///
+/// use init_trait::init_from_coroutine;
+///
+/// let squares = init_from_coroutine(#[coroutine] || {
+///     for i in 0..4 {
+///         yield i * i;
+///     }
+/// });
+///
+/// assert_eq!(squares, vec![0, 1, 4, 9]);
+/// ```
+pub fn init_from_coroutine<T, G: Coroutine<Yield = T, Return = ()>>(gen: G) -> Vec<T> {
+	let mut gen = pin!(gen);
+	let mut value = Vec::new();
+	
+	while let CoroutineState::Yielded(x) = gen.as_mut().resume(()) {
+		value.push(x);
+	}
+	
+	value
+}
@@ -0,0 +1,37 @@
+use strum::{EnumCount, IntoEnumIterator};
+
+use super::Init;
+
+/// Initialise an array with one element per variant of `E` by applying `elem` to each variant, in
+/// declaration order.
+///
+/// For a fieldless enum, the array can then be indexed with `variant as usize`. Using this with an
+/// `N` other than `E::COUNT` fails to compile.
+///
+/// The length can't be written as `E::COUNT` in the signature for a generic `E` without the
+/// unstable `generic_const_exprs` feature, so it is a separate parameter and
+/// `init_enum_indexed::<E, T>(..)` doesn't compile. Annotate the result as `[T; E::COUNT]` as below,
+/// or give the length explicitly with `init_enum_indexed::<E, T, _, { E::COUNT }>(..)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use strum::{EnumCount, EnumIter};
+/// use init_trait::init_enum_indexed;
+///
+/// #[derive(Clone, Copy, EnumCount, EnumIter)]
+/// enum Channel { Red, Green, Blue }
+///
+/// let gain: [f32; Channel::COUNT] = init_enum_indexed(|c| match c {
+///     Channel::Green => 1.5,
+///     _ => 1.0,
+/// });
+///
+/// assert_eq!(gain[Channel::Green as usize], 1.5);
+/// ```
+pub fn init_enum_indexed<E: EnumCount + IntoEnumIterator, T, F: FnMut(E) -> T, const N: usize>(mut elem: F) -> [T; N] {
+	const { assert!(N == E::COUNT, "the array length must match the number of variants") };
+	
+	let mut variants = E::iter();
+	<[T; N]>::init(|_| elem(variants.next().expect("enum iterator yielded fewer than COUNT variants")))
+}
@@ -0,0 +1,57 @@
+use az::SaturatingCast;
+
+use super::{Init, TypeEquals};
+
+/// Types of numbers, such as fixed-point numbers from the `fixed` crate, which can be initialised
+/// from any numeric value with a saturating conversion.
+///
+/// This is implemented for every type which implements `Init`. The conversions are the `az` cast
+/// traits, which `fixed` implements, so this crate doesn't depend on `fixed` itself. Depending on
+/// `fixed` brings in comparisons between its types and the primitives, which would stop the
+/// element type of an empty array literal like `[]` from being inferred.
+pub trait FixedInit<T, I, V = ()>: Init<T, I, V> {
+	/// Initialise an instance of this type using `value` by converting the result of applying `elem`
+	/// to each 'index', saturating values which are out of range for `T`.
+	///
+	/// `elem` can return any type which can be cast to `T` with `SaturatingCast`, such as `f64`, an
+	/// integer, or another fixed-point type.
+	///
+	/// # Panics
+	///
+	/// Panics if `elem` returns a NaN.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::FixedInit;
+	/// use fixed::types::I8F8;
+	///
+	/// let gains = Vec::<I8F8>::init_saturating_with(3, |i| i as f64 * 100.0);
+	///
+	/// assert_eq!(gains, [I8F8::ZERO, I8F8::from_num(100), I8F8::MAX]);
+	/// ```
+	fn init_saturating_with<S: SaturatingCast<T>, F: FnMut(I) -> S>(value: V, mut elem: F) -> Self {
+		Self::init_with(value, |i| elem(i).saturating_cast())
+	}
+	
+	/// Initialise an instance of this type by converting the result of applying `elem` to each
+	/// 'index', saturating values which are out of range for `T`.
+	///
+	/// This is syntax sugar for `init_saturating_with((), elem)`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::FixedInit;
+	/// use fixed::types::I16F16;
+	///
+	/// let ramp = <[I16F16; 4]>::init_saturating(|i| i as f64 / 4.0);
+	///
+	/// assert_eq!(ramp[2], I16F16::from_num(0.5));
+	/// ```
+	fn init_saturating<S: SaturatingCast<T>, F: FnMut(I) -> S>(elem: F) -> Self where V: TypeEquals<()> {
+		Self::init_saturating_with(().into(), elem)
+	}
+}
+
+impl<T, I, V, S: Init<T, I, V>> FixedInit<T, I, V> for S { }
@@ -0,0 +1,299 @@
+use super::Init;
+use super::guard::{init_ordered, unflatten, volume};
+
+/// A row index into a two-dimensional array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Row(pub usize);
+
+/// A column index into a two-dimensional array.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Column(pub usize);
+
+/// Additional initialisers for two-dimensional arrays, indexed by `[row, column]`.
+pub trait GridInit<T, const R: usize, const C: usize>: Init<T, [usize; 2]> {
+	/// Initialise a square grid by applying `elem` to each `[row, column]`, visiting the cells in
+	/// the order of a Hilbert curve.
+	///
+	/// The cells are still stored row-major, only the order in which `elem` is called changes.
+	/// Consecutive calls are always for adjacent cells, which can help closures with locality
+	/// sensitive state.
+	///
+	/// # Panics
+	///
+	/// Panics if the grid isn't square or its side length isn't a power of two.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::GridInit;
+	///
+	/// let mut order = Vec::new();
+	/// let grid = <[[usize; 2]; 2]>::init_hilbert_with(|[r, c]| { order.push([r, c]); r * 2 + c });
+	///
+	/// assert_eq!(grid, [[0, 1], [2, 3]]);
+	/// assert_eq!(order, [[0, 0], [1, 0], [1, 1], [0, 1]]);
+	/// ```
+	fn init_hilbert_with<F: FnMut([usize; 2]) -> T>(elem: F) -> Self;
+	
+	/// Initialise a grid by applying `elem` to each `(row, column)`, with the indices wrapped in
+	/// `Row` and `Column` so that they can't be swapped by accident.
+	///
+	/// The indices can also be converted into custom index types, as long as they implement
+	/// `From<Row>` and `From<Column>` respectively.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::{GridInit, Row, Column};
+	///
+	/// let grid = <[[usize; 3]; 2]>::init_typed(|(Row(r), Column(c))| r * 10 + c);
+	///
+	/// assert_eq!(grid, [[0, 1, 2], [10, 11, 12]]);
+	/// ```
+	fn init_typed<A: From<Row>, B: From<Column>, F: FnMut((A, B)) -> T>(elem: F) -> Self;
+	
+	/// Initialise a grid by applying `elem` to each row and column, passed as separate arguments.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::GridInit;
+	///
+	/// let grid = <[[f64; 3]; 2]>::init_2d(|r, c| r as f64 + c as f64 / 10.0);
+	///
+	/// assert_eq!(grid, [[0.0, 0.1, 0.2], [1.0, 1.1, 1.2]]);
+	/// ```
+	fn init_2d<F: FnMut(usize, usize) -> T>(elem: F) -> Self;
+	
+	/// Initialise a grid by applying `row` to each row index to build the whole row at once.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::GridInit;
+	///
+	/// let identity = <[[u8; 3]; 3]>::init_rows(|r| {
+	///     let mut row = [0; 3];
+	///     row[r] = 1;
+	///     row
+	/// });
+	///
+	/// assert_eq!(identity, [[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+	/// ```
+	fn init_rows<F: FnMut(usize) -> [T; C]>(row: F) -> Self;
+	
+	/// Initialise a square grid by applying `elem` to each `[row, column]`, visiting the cells in
+	/// Morton (Z-curve) order.
+	///
+	/// The Morton index of a cell interleaves the bits of its coordinates, with the column in the
+	/// lowest bit. As with `init_hilbert_with`, the cells are still stored row-major.
+	///
+	/// # Panics
+	///
+	/// Panics if the grid isn't square or its side length isn't a power of two.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::GridInit;
+	///
+	/// let mut order = Vec::new();
+	/// let grid = <[[usize; 2]; 2]>::init_morton_with(|[r, c]| { order.push([r, c]); r * 2 + c });
+	///
+	/// assert_eq!(grid, [[0, 1], [2, 3]]);
+	/// assert_eq!(order, [[0, 0], [0, 1], [1, 0], [1, 1]]);
+	/// ```
+	fn init_morton_with<F: FnMut([usize; 2]) -> T>(elem: F) -> Self;
+	
+	/// Initialise a grid by applying `col` to each column index to build the whole column at once.
+	///
+	/// This is the column analogue of `init_rows`, scattering each column into the row-major grid.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::GridInit;
+	///
+	/// let grid = <[[usize; 3]; 2]>::init_by_columns(|c| [c, c * 10]);
+	///
+	/// assert_eq!(grid, [[0, 1, 2], [0, 10, 20]]);
+	/// ```
+	fn init_by_columns<F: FnMut(usize) -> [T; R]>(col: F) -> Self;
+}
+
+impl<T, const R: usize, const C: usize> GridInit<T, R, C> for [[T; C]; R] {
+	fn init_hilbert_with<F: FnMut([usize; 2]) -> T>(mut elem: F) -> Self {
+		assert!(R == C, "Hilbert curve order needs a square grid, not {}x{}", R, C);
+		assert!(R.is_power_of_two() || R == 0, "Hilbert curve order needs a power of two side length, not {}", R);
+		
+		// SAFETY: `[[T; C]; R]` is laid out as `[T; R * C]`, and `hilbert_cell` is a bijection
+		unsafe {
+			init_ordered(R * C, |d| {
+				let [r, c] = hilbert_cell(R, d);
+				r * C + c
+			}, |p| elem([p / C, p % C]))
+		}
+	}
+	
+	fn init_typed<A: From<Row>, B: From<Column>, F: FnMut((A, B)) -> T>(mut elem: F) -> Self {
+		<Self as Init<T, [usize; 2]>>::init(|[r, c]| elem((Row(r).into(), Column(c).into())))
+	}
+	
+	fn init_2d<F: FnMut(usize, usize) -> T>(mut elem: F) -> Self {
+		<Self as Init<T, [usize; 2]>>::init(|[r, c]| elem(r, c))
+	}
+	
+	fn init_rows<F: FnMut(usize) -> [T; C]>(row: F) -> Self {
+		<Self as Init<[T; C], usize>>::init(row)
+	}
+	
+	fn init_morton_with<F: FnMut([usize; 2]) -> T>(mut elem: F) -> Self {
+		assert!(R == C, "Morton order needs a square grid, not {}x{}", R, C);
+		assert!(R.is_power_of_two() || R == 0, "Morton order needs a power of two side length, not {}", R);
+		
+		// SAFETY: `[[T; C]; R]` is laid out as `[T; R * C]`, and deinterleaving the bits of each
+		// index below `R * C` is a bijection onto the cells
+		unsafe {
+			init_ordered(R * C, |d| compact_bits(d >> 1) * C + compact_bits(d), |p| elem([p / C, p % C]))
+		}
+	}
+	
+	fn init_by_columns<F: FnMut(usize) -> [T; R]>(col: F) -> Self {
+		let mut columns = <[[T; R]; C]>::init(col).map(IntoIterator::into_iter);
+		
+		// Filling row by row takes the next element from each column in turn
+		<Self as Init<[T; C], usize>>::init(|_| <[T; C]>::init(|c| columns[c].next().unwrap()))
+	}
+}
+
+/// Additional initialisers for three-dimensional arrays, indexed by `[layer, row, column]`.
+pub trait Grid3Init<T, const L: usize, const R: usize, const C: usize>: Init<T, [usize; 3]> {
+	/// Initialise a 3D grid by applying `elem` to each layer, row and column, passed as separate
+	/// arguments.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::Grid3Init;
+	///
+	/// let grid = <[[[usize; 2]; 2]; 2]>::init_3d(|l, r, c| l * 100 + r * 10 + c);
+	///
+	/// assert_eq!(grid, [[[0, 1], [10, 11]], [[100, 101], [110, 111]]]);
+	/// ```
+	fn init_3d<F: FnMut(usize, usize, usize) -> T>(elem: F) -> Self;
+	
+	/// Initialise a 3D grid by applying `elem` to each `[layer, row, column]`, looping over the axes
+	/// in `axis_order` from outermost to innermost.
+	///
+	/// Axis `0` is the layer, `1` the row and `2` the column, so `[0, 1, 2]` is the usual order. The
+	/// cells are still stored layer-major, only the order in which `elem` is called changes.
+	///
+	/// # Panics
+	///
+	/// Panics if `axis_order` isn't a permutation of `[0, 1, 2]`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::Grid3Init;
+	///
+	/// let mut order = Vec::new();
+	/// let grid = <[[[u8; 2]; 1]; 2]>::init_3d_order([2, 0, 1], |[l, r, c]| { order.push([l, r, c]); 0 });
+	///
+	/// assert_eq!(order, [[0, 0, 0], [1, 0, 0], [0, 0, 1], [1, 0, 1]]);
+	/// ```
+	fn init_3d_order<F: FnMut([usize; 3]) -> T>(axis_order: [usize; 3], elem: F) -> Self;
+}
+
+impl<T, const L: usize, const R: usize, const C: usize> Grid3Init<T, L, R, C> for [[[T; C]; R]; L] {
+	fn init_3d<F: FnMut(usize, usize, usize) -> T>(mut elem: F) -> Self {
+		<Self as Init<T, [usize; 3]>>::init(|[l, r, c]| elem(l, r, c))
+	}
+	
+	fn init_3d_order<F: FnMut([usize; 3]) -> T>(axis_order: [usize; 3], mut elem: F) -> Self {
+		let mut sorted = axis_order;
+		sorted.sort_unstable();
+		assert!(sorted == [0, 1, 2], "axis order must be a permutation of [0, 1, 2], not {:?}", axis_order);
+		
+		let dims = [L, R, C];
+		let loop_dims = [dims[axis_order[0]], dims[axis_order[1]], dims[axis_order[2]]];
+		
+		// SAFETY: `[[[T; C]; R]; L]` is laid out as `[T; L * R * C]`, and permuting the axes of a
+		// row-major traversal visits every position exactly once
+		unsafe {
+			init_ordered(volume(dims), |d| {
+				let step = unflatten(d, loop_dims);
+				let mut index = [0; 3];
+				for (&axis, &i) in axis_order.iter().zip(step.iter()) {
+					index[axis] = i;
+				}
+				(index[0] * R + index[1]) * C + index[2]
+			}, |p| elem(unflatten(p, dims)))
+		}
+	}
+}
+
+/// Additional initialisers for four-dimensional arrays, indexed by `[block, layer, row, column]`.
+pub trait Grid4Init<T, const B: usize, const L: usize, const R: usize, const C: usize>: Init<T, [usize; 4]> {
+	/// Initialise a 4D grid by applying `elem` to each block, layer, row and column, passed as
+	/// separate arguments.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::Grid4Init;
+	///
+	/// let grid = <[[[[usize; 2]; 1]; 1]; 2]>::init_4d(|b, l, r, c| b * 1000 + l * 100 + r * 10 + c);
+	///
+	/// assert_eq!(grid, [[[[0, 1]]], [[[1000, 1001]]]]);
+	/// ```
+	fn init_4d<F: FnMut(usize, usize, usize, usize) -> T>(elem: F) -> Self;
+}
+
+impl<T, const B: usize, const L: usize, const R: usize, const C: usize> Grid4Init<T, B, L, R, C> for [[[[T; C]; R]; L]; B] {
+	fn init_4d<F: FnMut(usize, usize, usize, usize) -> T>(mut elem: F) -> Self {
+		<Self as Init<T, [usize; 4]>>::init(|[b, l, r, c]| elem(b, l, r, c))
+	}
+}
+
+/// Find the `[row, column]` of the `d`th cell along a Hilbert curve over an `n` × `n` grid.
+fn hilbert_cell(n: usize, d: usize) -> [usize; 2] {
+	let (mut r, mut c) = (0, 0);
+	let mut t = d;
+	let mut s = 1;
+	
+	while s < n {
+		let rc = 1 & (t / 2);
+		let rr = 1 & (t ^ rc);
+		
+		if rr == 0 {
+			if rc == 1 {
+				r = s - 1 - r;
+				c = s - 1 - c;
+			}
+			core::mem::swap(&mut r, &mut c);
+		}
+		
+		r += s * rr;
+		c += s * rc;
+		t /= 4;
+		s *= 2;
+	}
+	
+	[r, c]
+}
+
+/// Collect the even bits of `x` into the low half, dropping the odd bits.
+fn compact_bits(x: usize) -> usize {
+	let mut result = 0;
+	let mut bit = 0;
+	let mut x = x;
+	
+	while x != 0 {
+		result |= (x & 1) << bit;
+		x >>= 2;
+		bit += 1;
+	}
+	
+	result
+}
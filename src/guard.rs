@@ -0,0 +1,159 @@
+use core::convert::Infallible;
+use core::mem::{MaybeUninit, forget};
+use core::ptr;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use super::Vec;
+
+/// Drops the elements written so far to a partially initialised buffer, where the `k`th element
+/// written is at position `order(k)`.
+///
+/// The elements are dropped in the reverse of the order they were written in.
+pub struct PartialInit<T, O: Fn(usize) -> usize> {
+	pub ptr: *mut T,
+	pub done: usize,
+	pub order: O,
+}
+
+impl<T, O: Fn(usize) -> usize> Drop for PartialInit<T, O> {
+	fn drop(&mut self) {
+		for k in (0..self.done).rev() {
+			// SAFETY: the positions `order(0..done)` have been written and not yet moved out
+			unsafe { ptr::drop_in_place(self.ptr.add((self.order)(k))); }
+		}
+	}
+}
+
+/// The total number of elements in a nested array with the lengths `dims`, from the outermost in.
+///
+/// # Panics
+///
+/// Panics if the total overflows a `usize`, which is only possible for zero-sized elements.
+pub fn volume<const D: usize>(dims: [usize; D]) -> usize {
+	dims.iter().try_fold(1usize, |acc, &n| acc.checked_mul(n)).expect("array has more than usize::MAX elements")
+}
+
+/// Convert a flat row-major position into an index into a nested array with the lengths `dims`,
+/// from the outermost in.
+pub fn unflatten<const D: usize>(mut p: usize, dims: [usize; D]) -> [usize; D] {
+	let mut index = [0; D];
+	
+	for d in (0..D).rev() {
+		index[d] = p % dims[d];
+		p /= dims[d];
+	}
+	
+	index
+}
+
+/// Advance a SplitMix64 generator, returning its next output.
+#[cfg(any(feature = "std", feature = "alloc", all(feature = "debug_checks", debug_assertions)))]
+pub fn splitmix64(state: &mut u64) -> u64 {
+	*state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+	let mut x = *state;
+	x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+	x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+	x ^ (x >> 31)
+}
+
+/// Initialise a value made up of `len` contiguous `T`s by writing `elem(p)` to each position
+/// `p = order(k)` for `k` in `0..len`.
+///
+/// # Safety
+///
+/// `U` must have the same layout as `[T; len]` (e.g. a nested array of `T`), and `order` must be a
+/// permutation of `0..len`.
+pub unsafe fn init_ordered<U, T, O: Fn(usize) -> usize, F: FnMut(usize) -> T>(len: usize, order: O, mut elem: F) -> U {
+	match try_init_ordered(len, order, |p| Ok::<T, Infallible>(elem(p))) {
+		Ok(value) => value,
+		Err(never) => match never { },
+	}
+}
+
+/// Fallible version of `init_ordered`, which stops at the first error and drops the elements
+/// written so far.
+///
+/// # Safety
+///
+/// Same as `init_ordered`.
+pub unsafe fn try_init_ordered<U, T, E, O: Fn(usize) -> usize, F: FnMut(usize) -> Result<T, E>>(len: usize, order: O, mut elem: F) -> Result<U, E> {
+	let mut value = MaybeUninit::<U>::uninit();
+	let mut guard = PartialInit { ptr: value.as_mut_ptr() as *mut T, done: 0, order };
+	
+	while guard.done < len {
+		let p = (guard.order)(guard.done);
+		guard.ptr.add(p).write(elem(p)?);
+		guard.done += 1;
+	}
+	
+	forget(guard);
+	Ok(value.assume_init())
+}
+
+/// Initialise a `Vec` of length `length` by applying `elem` to each index.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn init_vec<T, F: FnMut(usize) -> T>(length: usize, mut elem: F) -> Vec<T> {
+	match try_init_vec(length, |i| Ok::<T, Infallible>(elem(i))) {
+		Ok(value) => value,
+		Err(never) => match never { },
+	}
+}
+
+/// Drops the elements pushed so far to a `Vec` in the reverse of the order they were pushed in,
+/// like `PartialInit`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+struct PushGuard<T>(Vec<T>);
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T> Drop for PushGuard<T> {
+	fn drop(&mut self) {
+		while self.0.pop().is_some() { }
+	}
+}
+
+/// Lengths below this are filled by `try_init_vec` with a plain `push` loop rather than by writing
+/// into the spare capacity directly.
+///
+/// Chosen from `benches/init_vec.rs`: below 8 elements the two are within noise of each other for
+/// every element size measured, while from 16 elements the direct write is clearly faster for
+/// small elements. The `push` loop is kept for short lengths since it needs no `unsafe`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub const VEC_PUSH_THRESHOLD: usize = 8;
+
+/// Fallible version of `init_vec`, which stops at the first error and drops the elements written
+/// so far.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn try_init_vec<T, E, F: FnMut(usize) -> Result<T, E>>(length: usize, mut elem: F) -> Result<Vec<T>, E> {
+	if length < VEC_PUSH_THRESHOLD {
+		let mut guard = PushGuard(Vec::with_capacity(length));
+		for i in 0..length {
+			let x = elem(i)?;
+			guard.0.push(x);
+		}
+		return Ok(core::mem::take(&mut guard.0));
+	}
+	
+	// SAFETY: the `Vec` has capacity for `length` elements
+	unsafe { fill_vec(Vec::with_capacity(length), length, elem) }
+}
+
+/// Write `elem(i)` for each `i` in `0..length` into the spare capacity of the empty `Vec` `value`,
+/// stopping at the first error and dropping the elements written so far.
+///
+/// # Safety
+///
+/// `value` must be empty, with a capacity of at least `length`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub unsafe fn fill_vec<T, E, F: FnMut(usize) -> Result<T, E>>(mut value: Vec<T>, length: usize, mut elem: F) -> Result<Vec<T>, E> {
+	let mut guard = PartialInit { ptr: value.as_mut_ptr(), done: 0, order: |i| i };
+	
+	while guard.done < length {
+		let x = elem(guard.done)?;
+		guard.ptr.add(guard.done).write(x);
+		guard.done += 1;
+	}
+	
+	forget(guard);
+	value.set_len(length);
+	Ok(value)
+}
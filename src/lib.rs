@@ -58,10 +58,33 @@ assert_eq!(road[1].number, 2);
 assert_eq!(road[2].number, 3);
 ```
 "##)]
+//!
+//! # Stable Rust
+//!
+//! By default this crate uses the nightly `const_generics` feature for the array impls. Enabling
+//! the `stable` feature drops the feature gate so the crate builds on a stable compiler, and adds
+//! an impl for [`GenericArray`](https://docs.rs/generic-array), whose length is a `typenum` type
+//! rather than a const generic:
+//!
+#![cfg_attr(feature = "stable", doc = "```rust")]
+#![cfg_attr(not(feature = "stable"), doc = "```rust,ignore")]
+//! use generic_array::{GenericArray, typenum::U4};
+//! use init_trait::Init;
+//!
+//! let arr = GenericArray::<usize, U4>::init(|i| i * 2);
+//!
+//! assert_eq!(arr.as_slice(), &[0, 2, 4, 6]);
+//! ```
+//!
+//! The main differences are that the length has to be written as a `typenum` type such as `U4`
+//! rather than a literal, and the result is a `GenericArray` rather than a plain array.
 
 #![no_std]
 
-#![feature(const_generics)]
+#![cfg_attr(not(feature = "stable"), feature(const_generics))]
+#![cfg_attr(feature = "step_trait", feature(step_trait))]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+#![cfg_attr(feature = "coroutine", feature(coroutine_trait))]
 
 #![doc(html_root_url = "https://docs.rs/init_trait/0.2.0")]
 
@@ -69,8 +92,115 @@ assert_eq!(road[2].number, 3);
 mod type_equals;
 use type_equals::TypeEquals;
 
+mod guard;
+
+mod macros;
+
+#[cfg(feature = "std")]
+mod thread;
+#[cfg(feature = "std")]
+pub use thread::{TimeoutError, init_with_timeout, init_to_sender};
+
+#[cfg(all(feature = "rayon", feature = "std"))]
+mod parallel;
+#[cfg(all(feature = "rayon", feature = "std"))]
+pub use parallel::par_init_from_indices;
+
+mod strategy;
+pub use strategy::{InitStrategy, Sequential, Reversed};
+#[cfg(feature = "std")]
+pub use strategy::Parallel;
+
+mod array;
+pub use array::{ArrayInit, LengthError, init_options_with, count_filled, init_mask, count_set, uninit_array, assume_init_array, init_const_permuted, init_from_const_slice};
+
+mod grid;
+pub use grid::{GridInit, Grid3Init, Grid4Init, Row, Column};
+
+mod nonzero;
+pub use nonzero::NonZeroInit;
+
+mod mod_array;
+pub use mod_array::ModArray;
+
+#[cfg(feature = "stable")]
+mod stable;
+
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rand")]
+pub use random::RandomInit;
+
+#[cfg(feature = "smallvec")]
+mod small_grid;
+#[cfg(feature = "smallvec")]
+pub use small_grid::SmallGrid;
+
+#[cfg(feature = "fixed")]
+mod fixed_point;
+#[cfg(feature = "fixed")]
+pub use fixed_point::FixedInit;
+
+#[cfg(feature = "strum")]
+mod enums;
+#[cfg(feature = "strum")]
+pub use enums::init_enum_indexed;
+
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "simd")]
+pub use simd::init_simd;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod collections;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use collections::{init_deque_rotated_with, init_bounded_deque_with, init_sorted_map, init_min_heap};
+#[cfg(feature = "std")]
+pub use collections::init_group_by;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod vec;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use vec::{VecInit, Cancelled, InitError, init_pair_with, init_jagged_with, init_encode, init_flat_map, init_normalised_with, init_concat, init_chunks, init_decode_records, init_zip_with, init_with_inverse, init_deinterleave, init_ragged, init_boxed_raw, reclaim};
+#[cfg(feature = "std")]
+pub use vec::{init_with_index, init_memoised};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod time;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use time::{init_backoff, init_backoff_capped};
+#[cfg(feature = "std")]
+pub use time::init_profiled_with;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod string;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod affine;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use affine::AffineInit;
+
+#[cfg(feature = "stream")]
+mod stream;
+#[cfg(feature = "stream")]
+pub use stream::{init_from_stream, init_with_async_buffered};
+
+#[cfg(all(feature = "tracing", any(feature = "std", feature = "alloc")))]
+mod traced;
+#[cfg(all(feature = "tracing", any(feature = "std", feature = "alloc")))]
+pub use traced::init_traced_with;
+
+#[cfg(feature = "coroutine")]
+mod coroutine;
+#[cfg(feature = "coroutine")]
+pub use coroutine::init_from_coroutine;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod owned;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use owned::OwnedInit;
+
 use core::marker::Sized;
-use core::mem::{MaybeUninit, transmute_copy, forget};
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -88,6 +218,9 @@ use alloc::vec::Vec;
 pub trait Init<T, I, V = ()>: Sized {
 	/// Initialise an instance of this type using `value` by applying `elem` to each 'index' of the type.
 	///
+	/// For the arrays and `Vec`, if `elem` panics the elements which were already initialised are
+	/// dropped in the reverse of the order they were initialised in, like local variables.
+	///
 	/// # Examples
 	///
 	#[cfg_attr(feature = "std", doc = r##"
@@ -123,60 +256,199 @@ pub trait Init<T, I, V = ()>: Sized {
 	}
 }
 
+/// Types which can be initialised by applying a fallible function to each 'index' of the type.
+pub trait TryInit<T, I, V = ()>: Sized {
+	/// The error returned when `elem` fails with an `E`.
+	///
+	/// For arrays this is just `E`, while `Vec` uses `InitError<E>` to also report allocation
+	/// failures.
+	type Error<E>;
+	
+	/// Initialise an instance of this type using `value` by applying `elem` to each 'index' of the
+	/// type, stopping at the first error.
+	///
+	/// Any elements which were already initialised when `elem` returns an error are dropped, in
+	/// the reverse of the order they were initialised in.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::TryInit;
+	///
+	/// let arr = <[u8; 4]>::try_init_with((), |i| (i as u8).checked_mul(100).ok_or(i));
+	///
+	/// assert_eq!(arr, Err(3));
+	/// ```
+	fn try_init_with<E, F: FnMut(I) -> Result<T, E>>(value: V, elem: F) -> Result<Self, Self::Error<E>>;
+	
+	/// Initialise an instance of this type by applying `elem` to each 'index' of the type,
+	/// stopping at the first error.
+	///
+	/// This is syntax sugar for `try_init_with((), elem)`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::TryInit;
+	///
+	/// let arr = <[u8; 3]>::try_init(|i| (i as u8).checked_mul(100).ok_or(i));
+	///
+	/// assert_eq!(arr, Ok([0, 100, 200]));
+	/// ```
+	fn try_init<E, F: FnMut(I) -> Result<T, E>>(elem: F) -> Result<Self, Self::Error<E>> where V: TypeEquals<()> {
+		Self::try_init_with(().into(), elem)
+	}
+}
+
 impl<T, const N: usize> Init<T, usize> for [T; N] {
-	fn init_with<F: FnMut(usize) -> T>(_: (), mut elem: F) -> Self {
-		let mut contents: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
-		
-		for i in 0..N {
-			contents[i] = MaybeUninit::new(elem(i));
-		}
-		
-		// FIXME: Replace with transmute once it works with const generic array sizes
-		let res = unsafe { transmute_copy(&contents) };
-		forget(contents);
-		res
+	fn init_with<F: FnMut(usize) -> T>(_: (), elem: F) -> Self {
+		// SAFETY: `[T; N]` is laid out as `N` contiguous `T`s, written in order
+		unsafe { guard::init_ordered(N, |i| i, elem) }
 	}
 }
 
 impl<T, const N1: usize, const N2: usize> Init<T, [usize; 2]> for [[T; N1]; N2] {
 	fn init_with<F: FnMut([usize; 2]) -> T>(_: (), mut elem: F) -> Self {
-		Self::init(|i1| <[T; N1]>::init(|i2| elem([i1, i2])))
+		let dims = [N2, N1];
+		// SAFETY: nested arrays are laid out as the product of their lengths contiguous `T`s, written in order
+		unsafe { guard::init_ordered(guard::volume(dims), |p| p, |p| elem(guard::unflatten(p, dims))) }
 	}
 }
 
 impl<T, const N1: usize, const N2: usize, const N3: usize> Init<T, [usize; 3]> for [[[T; N1]; N2]; N3] {
 	fn init_with<F: FnMut([usize; 3]) -> T>(_: (), mut elem: F) -> Self {
-		Self::init(|i1| <[[T; N1]; N2]>::init(|[i2, i3]: [usize; 2]| elem([i1, i2, i3])))
+		let dims = [N3, N2, N1];
+		// SAFETY: nested arrays are laid out as the product of their lengths contiguous `T`s, written in order
+		unsafe { guard::init_ordered(guard::volume(dims), |p| p, |p| elem(guard::unflatten(p, dims))) }
 	}
 }
 
 impl<T, const N1: usize, const N2: usize, const N3: usize, const N4: usize> Init<T, [usize; 4]> for [[[[T; N1]; N2]; N3]; N4] {
 	fn init_with<F: FnMut([usize; 4]) -> T>(_: (), mut elem: F) -> Self {
-		Self::init(|i1| <[[[T; N1]; N2]; N3]>::init(|[i2, i3, i4]: [usize; 3]| elem([i1, i2, i3, i4])))
+		let dims = [N4, N3, N2, N1];
+		// SAFETY: nested arrays are laid out as the product of their lengths contiguous `T`s, written in order
+		unsafe { guard::init_ordered(guard::volume(dims), |p| p, |p| elem(guard::unflatten(p, dims))) }
 	}
 }
 
 impl<T, const N1: usize, const N2: usize, const N3: usize, const N4: usize, const N5: usize> Init<T, [usize; 5]> for [[[[[T; N1]; N2]; N3]; N4]; N5] {
 	fn init_with<F: FnMut([usize; 5]) -> T>(_: (), mut elem: F) -> Self {
-		Self::init(|i1| <[[[[T; N1]; N2]; N3]; N4]>::init(|[i2, i3, i4, i5]: [usize; 4]| elem([i1, i2, i3, i4, i5])))
+		let dims = [N5, N4, N3, N2, N1];
+		// SAFETY: nested arrays are laid out as the product of their lengths contiguous `T`s, written in order
+		unsafe { guard::init_ordered(guard::volume(dims), |p| p, |p| elem(guard::unflatten(p, dims))) }
 	}
 }
 
 impl<T, const N1: usize, const N2: usize, const N3: usize, const N4: usize, const N5: usize, const N6: usize> Init<T, [usize; 6]> for [[[[[[T; N1]; N2]; N3]; N4]; N5]; N6] {
 	fn init_with<F: FnMut([usize; 6]) -> T>(_: (), mut elem: F) -> Self {
-		Self::init(|i1| <[[[[[T; N1]; N2]; N3]; N4]; N5]>::init(|[i2, i3, i4, i5, i6]: [usize; 5]| elem([i1, i2, i3, i4, i5, i6])))
+		let dims = [N6, N5, N4, N3, N2, N1];
+		// SAFETY: nested arrays are laid out as the product of their lengths contiguous `T`s, written in order
+		unsafe { guard::init_ordered(guard::volume(dims), |p| p, |p| elem(guard::unflatten(p, dims))) }
+	}
+}
+
+impl<T, const N: usize> TryInit<T, usize> for [T; N] {
+	type Error<E> = E;
+	
+	fn try_init_with<E, F: FnMut(usize) -> Result<T, E>>(_: (), elem: F) -> Result<Self, E> {
+		// SAFETY: `[T; N]` is laid out as `N` contiguous `T`s, written in order
+		unsafe { guard::try_init_ordered(N, |i| i, elem) }
 	}
 }
 
+impl<T, const N1: usize, const N2: usize> TryInit<T, [usize; 2]> for [[T; N1]; N2] {
+	type Error<E> = E;
+	
+	fn try_init_with<E, F: FnMut([usize; 2]) -> Result<T, E>>(_: (), mut elem: F) -> Result<Self, E> {
+		let dims = [N2, N1];
+		// SAFETY: nested arrays are laid out as the product of their lengths contiguous `T`s, written in order
+		unsafe { guard::try_init_ordered(guard::volume(dims), |p| p, |p| elem(guard::unflatten(p, dims))) }
+	}
+}
+
+impl<T, const N1: usize, const N2: usize, const N3: usize> TryInit<T, [usize; 3]> for [[[T; N1]; N2]; N3] {
+	type Error<E> = E;
+	
+	fn try_init_with<E, F: FnMut([usize; 3]) -> Result<T, E>>(_: (), mut elem: F) -> Result<Self, E> {
+		let dims = [N3, N2, N1];
+		// SAFETY: nested arrays are laid out as the product of their lengths contiguous `T`s, written in order
+		unsafe { guard::try_init_ordered(guard::volume(dims), |p| p, |p| elem(guard::unflatten(p, dims))) }
+	}
+}
+
+impl<T, const N1: usize, const N2: usize, const N3: usize, const N4: usize> TryInit<T, [usize; 4]> for [[[[T; N1]; N2]; N3]; N4] {
+	type Error<E> = E;
+	
+	fn try_init_with<E, F: FnMut([usize; 4]) -> Result<T, E>>(_: (), mut elem: F) -> Result<Self, E> {
+		let dims = [N4, N3, N2, N1];
+		// SAFETY: nested arrays are laid out as the product of their lengths contiguous `T`s, written in order
+		unsafe { guard::try_init_ordered(guard::volume(dims), |p| p, |p| elem(guard::unflatten(p, dims))) }
+	}
+}
+
+impl<T, const N1: usize, const N2: usize, const N3: usize, const N4: usize, const N5: usize> TryInit<T, [usize; 5]> for [[[[[T; N1]; N2]; N3]; N4]; N5] {
+	type Error<E> = E;
+	
+	fn try_init_with<E, F: FnMut([usize; 5]) -> Result<T, E>>(_: (), mut elem: F) -> Result<Self, E> {
+		let dims = [N5, N4, N3, N2, N1];
+		// SAFETY: nested arrays are laid out as the product of their lengths contiguous `T`s, written in order
+		unsafe { guard::try_init_ordered(guard::volume(dims), |p| p, |p| elem(guard::unflatten(p, dims))) }
+	}
+}
+
+impl<T, const N1: usize, const N2: usize, const N3: usize, const N4: usize, const N5: usize, const N6: usize> TryInit<T, [usize; 6]> for [[[[[[T; N1]; N2]; N3]; N4]; N5]; N6] {
+	type Error<E> = E;
+	
+	fn try_init_with<E, F: FnMut([usize; 6]) -> Result<T, E>>(_: (), mut elem: F) -> Result<Self, E> {
+		let dims = [N6, N5, N4, N3, N2, N1];
+		// SAFETY: nested arrays are laid out as the product of their lengths contiguous `T`s, written in order
+		unsafe { guard::try_init_ordered(guard::volume(dims), |p| p, |p| elem(guard::unflatten(p, dims))) }
+	}
+}
+
+/// Builds a `Vec` with `length` elements.
+///
+/// The element type can be anything sized, including boxed trait objects, for example to build a
+/// dispatch table:
+///
+/// ```rust
+/// use init_trait::Init;
+///
+/// let handlers = Vec::<Box<dyn Fn(u32) -> u32>>::init_with(3, |i| match i {
+///     0 => Box::new(|x| x + 1),
+///     1 => Box::new(|x| x * 2),
+///     offset => Box::new(move |x| x + offset as u32),
+/// });
+///
+/// assert_eq!(handlers.iter().map(|h| h(10)).collect::<Vec<_>>(), vec![11, 20, 12]);
+/// ```
 #[cfg(any(feature = "std", feature = "alloc"))]
 impl<T> Init<T, usize, usize> for Vec<T> {
-	fn init_with<F: FnMut(usize) -> T>(length: usize, mut elem: F) -> Self {
-		let mut value = Vec::with_capacity(length);
-		
-		for i in 0..length {
-			value.push(elem(i));
-		}
+	fn init_with<F: FnMut(usize) -> T>(length: usize, elem: F) -> Self {
+		guard::init_vec(length, elem)
+	}
+}
+
+/// Reports allocation failures separately from errors returned by `elem`.
+///
+/// ```rust
+/// use init_trait::{TryInit, InitError};
+///
+/// let parsed = Vec::<u8>::try_init_with(3, |i| ["1", "x", "3"][i].parse());
+/// assert!(matches!(parsed, Err(InitError::Closure(_))));
+///
+/// let huge = Vec::<u64>::try_init_with(usize::MAX, |_| Ok::<_, ()>(0));
+/// assert!(matches!(huge, Err(InitError::Capacity(_))));
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T> TryInit<T, usize, usize> for Vec<T> {
+	type Error<E> = InitError<E>;
+	
+	fn try_init_with<E, F: FnMut(usize) -> Result<T, E>>(length: usize, elem: F) -> Result<Self, InitError<E>> {
+		let mut value = Vec::new();
+		value.try_reserve_exact(length)?;
 		
-		value
+		// SAFETY: the `Vec` has capacity for `length` elements
+		unsafe { guard::fill_vec(value, length, elem) }.map_err(InitError::Closure)
 	}
 }
@@ -0,0 +1,30 @@
+/// Initialise a multi-dimensional array without spelling out the `Init` type annotations.
+///
+/// Each dimension is written as `[usize; N]`, from the outermost in, followed by a closure taking
+/// one index per dimension. Any number of dimensions is supported, and the element type is
+/// inferred from the closure:
+///
+/// * `init!([usize; N] => |i| expr)` builds `[T; N]`
+/// * `init!([usize; R][usize; C] => |r, c| expr)` builds `[[T; C]; R]`
+/// * `init!([usize; X][usize; Y][usize; Z] => |x, y, z| expr)` builds `[[[T; Z]; Y]; X]`
+///
+/// The closure is called in the same order as the equivalent `Init::init`.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init;
+///
+/// let grid = init!([usize; 2][usize; 3] => |r, c| r * 3 + c);
+///
+/// assert_eq!(grid, [[0, 1, 2], [3, 4, 5]]);
+/// ```
+#[macro_export]
+macro_rules! init {
+	([usize; $n:expr] => |$i:pat| $elem:expr) => {
+		<[_; $n] as $crate::Init<_, usize>>::init(|$i: usize| $elem)
+	};
+	([usize; $n:expr] $([usize; $rest:expr])+ => |$i:pat, $($is:pat),+| $elem:expr) => {
+		<[_; $n] as $crate::Init<_, usize>>::init(|$i: usize| $crate::init!($([usize; $rest])+ => |$($is),+| $elem))
+	};
+}
@@ -0,0 +1,106 @@
+use core::ops::{Index, IndexMut};
+
+use super::Init;
+
+/// An array whose indices wrap around modulo `N`, for cyclic tables such as wavetables.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::{Init, ModArray};
+///
+/// let table = ModArray::<u8, 4>::init(|i| i as u8 * 10);
+///
+/// assert_eq!(table[6], 20);
+/// assert_eq!(*table.get_signed(-1), 30);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ModArray<T, const N: usize>([T; N]);
+
+impl<T, const N: usize> ModArray<T, N> {
+	/// Wrap an existing array.
+	pub fn new(arr: [T; N]) -> Self {
+		ModArray(arr)
+	}
+	
+	/// Unwrap the underlying array.
+	pub fn into_inner(self) -> [T; N] {
+		self.0
+	}
+	
+	/// The underlying array.
+	pub fn as_array(&self) -> &[T; N] {
+		&self.0
+	}
+	
+	/// Get a reference to the element at `i mod N`.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is `0`.
+	pub fn get(&self, i: usize) -> &T {
+		&self.0[i % N]
+	}
+	
+	/// Get a mutable reference to the element at `i mod N`.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is `0`.
+	pub fn get_mut(&mut self, i: usize) -> &mut T {
+		&mut self.0[i % N]
+	}
+	
+	/// Get a reference to the element at `i mod N`, where negative indices count back from the
+	/// end, wrapping as many times as needed.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is `0`.
+	pub fn get_signed(&self, i: isize) -> &T {
+		&self.0[signed_mod(i, N)]
+	}
+	
+	/// Get a mutable reference to the element at `i mod N`, where negative indices count back from
+	/// the end, wrapping as many times as needed.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is `0`.
+	pub fn get_signed_mut(&mut self, i: isize) -> &mut T {
+		&mut self.0[signed_mod(i, N)]
+	}
+}
+
+/// `i mod n`, always in `0..n`.
+fn signed_mod(i: isize, n: usize) -> usize {
+	assert!(n > 0, "can't index into an empty ModArray");
+	let r = i.unsigned_abs() % n;
+	if i < 0 && r != 0 { n - r } else { r }
+}
+
+impl<T, const N: usize> Index<usize> for ModArray<T, N> {
+	type Output = T;
+	
+	fn index(&self, i: usize) -> &T {
+		self.get(i)
+	}
+}
+
+impl<T, const N: usize> IndexMut<usize> for ModArray<T, N> {
+	fn index_mut(&mut self, i: usize) -> &mut T {
+		self.get_mut(i)
+	}
+}
+
+impl<T, const N: usize> From<[T; N]> for ModArray<T, N> {
+	fn from(arr: [T; N]) -> Self {
+		ModArray(arr)
+	}
+}
+
+impl<T, const N: usize> Init<T, usize> for ModArray<T, N> {
+	fn init_with<F: FnMut(usize) -> T>(_: (), elem: F) -> Self {
+		ModArray(<[T; N]>::init(elem))
+	}
+}
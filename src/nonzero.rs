@@ -0,0 +1,43 @@
+use core::num::{
+	NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+	NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+};
+
+use super::TryInit;
+
+/// Arrays of non-zero integers which can be initialised from a function returning plain integers.
+pub trait NonZeroInit<P, const N: usize>: Sized {
+	/// Initialise an array of non-zero integers by applying `elem` to each index, returning the
+	/// first index where `elem` returned zero as an error.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use core::num::NonZeroU32;
+	/// use init_trait::NonZeroInit;
+	///
+	/// let arr = <[NonZeroU32; 3]>::init_nonzero(|i| i as u32 + 1).unwrap();
+	/// assert_eq!(arr[2].get(), 3);
+	///
+	/// let err = <[NonZeroU32; 3]>::init_nonzero(|i| i as u32);
+	/// assert_eq!(err, Err(0));
+	/// ```
+	fn init_nonzero<F: FnMut(usize) -> P>(elem: F) -> Result<Self, usize>;
+}
+
+macro_rules! impl_nonzero_init {
+	($($nonzero:ident($prim:ty)),* $(,)?) => {
+		$(
+			impl<const N: usize> NonZeroInit<$prim, N> for [$nonzero; N] {
+				fn init_nonzero<F: FnMut(usize) -> $prim>(mut elem: F) -> Result<Self, usize> {
+					Self::try_init(|i| $nonzero::new(elem(i)).ok_or(i))
+				}
+			}
+		)*
+	};
+}
+
+impl_nonzero_init! {
+	NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128), NonZeroUsize(usize),
+	NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128), NonZeroIsize(isize),
+}
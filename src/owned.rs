@@ -0,0 +1,49 @@
+#[cfg(feature = "std")]
+use std::borrow::ToOwned;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::borrow::ToOwned;
+
+use super::{Init, TypeEquals};
+
+/// Borrowed types whose owned form can be initialised by applying a function to each 'index'.
+///
+/// This is implemented for every `ToOwned` type whose `Owned` type implements `Init`, so
+/// `[T]` builds a `Vec<T>` and `str` builds a `String`.
+pub trait OwnedInit<T, I, V = ()>: ToOwned where Self::Owned: Init<T, I, V> {
+	/// Initialise the owned form of this type using `value` by applying `elem` to each 'index'.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::OwnedInit;
+	///
+	/// let bytes = <[u8]>::init_owned_with(3, |i| i as u8 * 2);
+	/// let name = str::init_owned_with(3, |i| (b'x' + i as u8) as char);
+	///
+	/// assert_eq!(bytes, vec![0, 2, 4]);
+	/// assert_eq!(name, "xyz");
+	/// ```
+	fn init_owned_with<F: FnMut(I) -> T>(value: V, elem: F) -> Self::Owned {
+		Self::Owned::init_with(value, elem)
+	}
+	
+	/// Initialise the owned form of this type by applying `elem` to each 'index'.
+	///
+	/// This is syntax sugar for `init_owned_with((), elem)`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::OwnedInit;
+	///
+	/// let arr = <[u8; 3]>::init_owned(|i| i as u8);
+	///
+	/// assert_eq!(arr, [0, 1, 2]);
+	/// ```
+	fn init_owned<F: FnMut(I) -> T>(elem: F) -> Self::Owned where V: TypeEquals<()> {
+		Self::init_owned_with(().into(), elem)
+	}
+}
+
+impl<T, I, V, B: ToOwned + ?Sized> OwnedInit<T, I, V> for B where B::Owned: Init<T, I, V> { }
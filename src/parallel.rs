@@ -0,0 +1,25 @@
+use rayon::iter::IndexedParallelIterator;
+
+use super::Vec;
+
+/// Initialise a `Vec` in parallel by applying `elem` to each index produced by `indices`, storing
+/// the results in the order of the iterator rather than the order they finish in.
+///
+/// This lets callers choose how the indices are split between threads, for example with
+/// `with_min_len` or `step_by`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rayon::prelude::*;
+/// use init_trait::par_init_from_indices;
+///
+/// let evens = par_init_from_indices((0..8).into_par_iter().step_by(2), |i| i * i);
+///
+/// assert_eq!(evens, vec![0, 4, 16, 36]);
+/// ```
+pub fn par_init_from_indices<T: Send, It: IndexedParallelIterator<Item = usize>, F: Fn(usize) -> T + Sync + Send>(indices: It, elem: F) -> Vec<T> {
+	let mut value = Vec::new();
+	indices.map(elem).collect_into_vec(&mut value);
+	value
+}
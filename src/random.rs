@@ -0,0 +1,47 @@
+use rand::{Rng, distributions::{Distribution, Standard}};
+
+use super::{Init, TypeEquals};
+
+/// Types which can be initialised by filling each 'index' with a random value.
+///
+/// This is implemented for every type which implements `Init`.
+pub trait RandomInit<T, I, V = ()>: Init<T, I, V> {
+	/// Initialise an instance of this type using `value` by filling each 'index' with a value
+	/// drawn from `rng`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::RandomInit;
+	/// use rand::{SeedableRng, rngs::StdRng};
+	///
+	/// let mut rng = StdRng::seed_from_u64(1);
+	/// let vec = Vec::<u8>::init_random_with(16, &mut rng);
+	///
+	/// assert_eq!(vec.len(), 16);
+	/// ```
+	fn init_random_with<R: Rng + ?Sized>(value: V, rng: &mut R) -> Self where Standard: Distribution<T> {
+		Self::init_with(value, |_| rng.gen())
+	}
+	
+	/// Initialise an instance of this type by filling each 'index' with a value drawn from `rng`.
+	///
+	/// This is syntax sugar for `init_random_with((), rng)`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::RandomInit;
+	/// use rand::{SeedableRng, rngs::StdRng};
+	///
+	/// let mut rng = StdRng::seed_from_u64(1);
+	/// let key = <[u8; 16]>::init_random(&mut rng);
+	///
+	/// assert_eq!(key, <[u8; 16]>::init_random(&mut StdRng::seed_from_u64(1)));
+	/// ```
+	fn init_random<R: Rng + ?Sized>(rng: &mut R) -> Self where Standard: Distribution<T>, V: TypeEquals<()> {
+		Self::init_random_with(().into(), rng)
+	}
+}
+
+impl<T, I, V, S: Init<T, I, V>> RandomInit<T, I, V> for S { }
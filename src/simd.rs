@@ -0,0 +1,29 @@
+use core::simd::{Simd, SimdElement};
+
+use super::Init;
+
+impl<T: SimdElement, const LANES: usize> Init<T, usize> for Simd<T, LANES> {
+	fn init_with<F: FnMut(usize) -> T>(_: (), elem: F) -> Self {
+		Simd::from_array(<[T; LANES]>::init(elem))
+	}
+}
+
+/// Initialise a SIMD vector by applying `elem` to each lane index.
+///
+/// This is the same as `Simd::<T, LANES>::init(elem)`.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(portable_simd)]
+///
+/// use core::simd::Simd;
+/// use init_trait::init_simd;
+///
+/// let v: Simd<f32, 4> = init_simd(|i| i as f32);
+///
+/// assert_eq!(v.to_array(), [0.0, 1.0, 2.0, 3.0]);
+/// ```
+pub fn init_simd<T: SimdElement, const LANES: usize, F: FnMut(usize) -> T>(elem: F) -> Simd<T, LANES> {
+	Simd::init(elem)
+}
@@ -0,0 +1,87 @@
+use core::ops::{Index, IndexMut};
+
+use smallvec::SmallVec;
+
+use super::Init;
+
+/// A two-dimensional grid with a run-time size, which stores up to `INLINE` cells inline before
+/// spilling to the heap.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::{Init, SmallGrid};
+///
+/// let grid = SmallGrid::<usize, 16>::init_with([2, 3], |[r, c]| r * 3 + c);
+///
+/// assert_eq!(grid[[1, 2]], 5);
+/// assert!(!grid.spilled());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SmallGrid<T, const INLINE: usize> {
+	cells: SmallVec<[T; INLINE]>,
+	columns: usize,
+	rows: usize,
+}
+
+impl<T, const INLINE: usize> SmallGrid<T, INLINE> {
+	/// The number of rows in the grid.
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+	
+	/// The number of columns in the grid.
+	pub fn columns(&self) -> usize {
+		self.columns
+	}
+	
+	/// Get a reference to the cell at `[row, column]`, or `None` if it's out of bounds.
+	pub fn get(&self, [r, c]: [usize; 2]) -> Option<&T> {
+		if r < self.rows && c < self.columns { self.cells.get(r * self.columns + c) } else { None }
+	}
+	
+	/// Get a mutable reference to the cell at `[row, column]`, or `None` if it's out of bounds.
+	pub fn get_mut(&mut self, [r, c]: [usize; 2]) -> Option<&mut T> {
+		if r < self.rows && c < self.columns { self.cells.get_mut(r * self.columns + c) } else { None }
+	}
+	
+	/// Whether the cells have been moved to the heap because there are more than `INLINE` of them.
+	pub fn spilled(&self) -> bool {
+		self.cells.spilled()
+	}
+	
+	/// The cells in row-major order.
+	pub fn as_slice(&self) -> &[T] {
+		&self.cells
+	}
+}
+
+impl<T, const INLINE: usize> Init<T, [usize; 2], [usize; 2]> for SmallGrid<T, INLINE> {
+	fn init_with<F: FnMut([usize; 2]) -> T>([rows, columns]: [usize; 2], mut elem: F) -> Self {
+		let mut cells = SmallVec::with_capacity(rows.checked_mul(columns).expect("capacity overflow"));
+		
+		for r in 0..rows {
+			for c in 0..columns {
+				cells.push(elem([r, c]));
+			}
+		}
+		
+		SmallGrid { cells, columns, rows }
+	}
+}
+
+impl<T, const INLINE: usize> Index<[usize; 2]> for SmallGrid<T, INLINE> {
+	type Output = T;
+	
+	fn index(&self, index: [usize; 2]) -> &T {
+		let (rows, columns) = (self.rows, self.columns);
+		self.get(index).unwrap_or_else(|| panic!("index {:?} out of bounds for {}x{} grid", index, rows, columns))
+	}
+}
+
+impl<T, const INLINE: usize> IndexMut<[usize; 2]> for SmallGrid<T, INLINE> {
+	fn index_mut(&mut self, index: [usize; 2]) -> &mut T {
+		let (rows, columns) = (self.rows, self.columns);
+		self.get_mut(index).unwrap_or_else(|| panic!("index {:?} out of bounds for {}x{} grid", index, rows, columns))
+	}
+}
@@ -0,0 +1,9 @@
+use generic_array::{ArrayLength, GenericArray, sequence::GenericSequence};
+
+use super::Init;
+
+impl<T, N: ArrayLength> Init<T, usize> for GenericArray<T, N> {
+	fn init_with<F: FnMut(usize) -> T>(_: (), elem: F) -> Self {
+		GenericArray::generate(elem)
+	}
+}
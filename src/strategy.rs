@@ -0,0 +1,51 @@
+#[cfg(feature = "std")]
+use std::thread;
+
+#[cfg(feature = "std")]
+use super::ArrayInit;
+use super::Init;
+use super::guard::init_ordered;
+
+/// A traversal order for initialising an array, chosen at compile time by a zero-sized marker
+/// type.
+///
+/// This is generic over the closure type `F`, so that each strategy can put its own bounds on it.
+pub trait InitStrategy<T, F> {
+	/// Initialise an array by applying `elem` to each index, in the order of this strategy.
+	fn init_array<const N: usize>(elem: F) -> [T; N];
+}
+
+/// Visits the indices in increasing order, like `init`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Sequential;
+
+/// Visits the indices in decreasing order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Reversed;
+
+/// Splits the indices into one contiguous stripe per available CPU, filled in parallel, like
+/// `ArrayInit::init_striped_with`.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Parallel;
+
+impl<T, F: FnMut(usize) -> T> InitStrategy<T, F> for Sequential {
+	fn init_array<const N: usize>(elem: F) -> [T; N] {
+		<[T; N]>::init(elem)
+	}
+}
+
+impl<T, F: FnMut(usize) -> T> InitStrategy<T, F> for Reversed {
+	fn init_array<const N: usize>(elem: F) -> [T; N] {
+		// SAFETY: `[T; N]` is laid out as `N` contiguous `T`s, and reversing `0..N` is a bijection
+		unsafe { init_ordered(N, |k| N - 1 - k, elem) }
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T: Send, F: Fn(usize) -> T + Sync> InitStrategy<T, F> for Parallel {
+	fn init_array<const N: usize>(elem: F) -> [T; N] {
+		let n_threads = thread::available_parallelism().map_or(1, |n| n.get());
+		<[T; N]>::init_striped_with(n_threads, elem)
+	}
+}
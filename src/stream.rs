@@ -0,0 +1,51 @@
+use core::future::{Future, poll_fn};
+use core::pin::pin;
+
+use futures_core::Stream;
+use futures_util::stream::{self, StreamExt};
+
+use super::Vec;
+
+/// Initialise a `Vec` from every item of `stream`, reserving space for the stream's lower size
+/// hint up front.
+///
+/// # Examples
+///
+/// ```rust
+/// use futures::{executor::block_on, stream};
+/// use init_trait::init_from_stream;
+///
+/// let vec = block_on(init_from_stream(stream::iter("abc".chars())));
+///
+/// assert_eq!(vec, vec!['a', 'b', 'c']);
+/// ```
+pub async fn init_from_stream<T, S: Stream<Item = T>>(stream: S) -> Vec<T> {
+	let mut stream = pin!(stream);
+	let mut value = Vec::with_capacity(stream.size_hint().0);
+	
+	while let Some(x) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+		value.push(x);
+	}
+	
+	value
+}
+
+/// Initialise a `Vec` of length `length` by awaiting `elem(i)` for each index, keeping at most
+/// `concurrency` of the futures in flight at once.
+///
+/// The futures may complete in any order, but the elements are stored in index order. A
+/// `concurrency` of `0` is treated as `1`.
+///
+/// # Examples
+///
+/// ```rust
+/// use futures::executor::block_on;
+/// use init_trait::init_with_async_buffered;
+///
+/// let vec = block_on(init_with_async_buffered(4, 2, |i| async move { i * 10 }));
+///
+/// assert_eq!(vec, vec![0, 10, 20, 30]);
+/// ```
+pub async fn init_with_async_buffered<T, Fut: Future<Output = T>, F: FnMut(usize) -> Fut>(length: usize, concurrency: usize, elem: F) -> Vec<T> {
+	init_from_stream(stream::iter(0..length).map(elem).buffered(concurrency.max(1))).await
+}
@@ -0,0 +1,25 @@
+use super::Init;
+
+#[cfg(feature = "std")]
+use std::{borrow::Cow, string::String};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{borrow::Cow, string::String};
+
+impl Init<char, usize, usize> for String {
+	fn init_with<F: FnMut(usize) -> char>(length: usize, mut elem: F) -> Self {
+		let mut value = String::with_capacity(length);
+		
+		for i in 0..length {
+			value.push(elem(i));
+		}
+		
+		value
+	}
+}
+
+impl Init<char, usize, usize> for Cow<'_, str> {
+	fn init_with<F: FnMut(usize) -> char>(length: usize, elem: F) -> Self {
+		Cow::Owned(String::init_with(length, elem))
+	}
+}
@@ -0,0 +1,162 @@
+use core::fmt;
+use core::mem::forget;
+use core::ptr;
+use core::time::Duration;
+
+use std::panic::resume_unwind;
+use std::sync::mpsc::{self, RecvTimeoutError, SendError, Sender};
+use std::thread;
+use std::vec::Vec;
+
+use super::guard::PartialInit;
+
+/// A pointer which can be sent to another thread, for writing disjoint parts of a buffer.
+struct SendPtr<T>(*mut T);
+
+unsafe impl<T: Send> Send for SendPtr<T> { }
+
+impl<T> Clone for SendPtr<T> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T> Copy for SendPtr<T> { }
+
+impl<T> SendPtr<T> {
+	fn get(self) -> *mut T {
+		self.0
+	}
+}
+
+/// Write `elem(i)` to `ptr.add(i)` for each `i` in `0..len`, splitting the indexes into
+/// `n_threads` contiguous stripes which are filled in parallel.
+///
+/// If `elem` panics on any thread, every element which was written is dropped and the panic is
+/// resumed on the calling thread once all threads have finished.
+///
+/// # Safety
+///
+/// `ptr` must be valid for writing `len` values of `T`.
+pub unsafe fn fill_striped<T: Send, F: Fn(usize) -> T + Sync>(ptr: *mut T, len: usize, n_threads: usize, elem: &F) {
+	let n_threads = n_threads.max(1).min(len.max(1));
+	let stripe = len.div_ceil(n_threads);
+	let base = SendPtr(ptr);
+	
+	thread::scope(|s| {
+		let handles: Vec<_> = (0..n_threads).map(|t| {
+			let start = (t * stripe).min(len);
+			let end = (start + stripe).min(len);
+			let handle = s.spawn(move || {
+				let mut guard = PartialInit { ptr: base.get().add(start), done: 0, order: |k| k };
+				
+				for i in start..end {
+					guard.ptr.add(guard.done).write(elem(i));
+					guard.done += 1;
+				}
+				
+				forget(guard);
+			});
+			(start..end, handle)
+		}).collect();
+		
+		let mut finished = Vec::with_capacity(n_threads);
+		let mut panic = None;
+		
+		for (stripe, handle) in handles {
+			match handle.join() {
+				Ok(()) => finished.push(stripe),
+				Err(payload) => { panic.get_or_insert(payload); },
+			}
+		}
+		
+		if let Some(payload) = panic {
+			for stripe in finished {
+				ptr::drop_in_place(ptr::slice_from_raw_parts_mut(base.get().add(stripe.start), stripe.len()));
+			}
+			resume_unwind(payload);
+		}
+	});
+}
+
+/// An error from an initialisation where one element took longer than the allowed time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimeoutError {
+	/// The index of the element which timed out.
+	pub index: usize,
+}
+
+impl fmt::Display for TimeoutError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "initialising element {} timed out", self.index)
+	}
+}
+
+impl std::error::Error for TimeoutError { }
+
+/// Initialise a `Vec` of length `length` by applying `elem` to each index on a worker thread,
+/// giving up if any single element takes longer than `per_elem`.
+///
+/// If an element times out, the elements built so far are dropped and the error carries its
+/// index. The worker thread can't be interrupted, so the slow call keeps running in the background
+/// and the worker stops once it returns. If `elem` panics, the panic is propagated.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use init_trait::init_with_timeout;
+///
+/// let vec = init_with_timeout(3, Duration::from_secs(5), |i| i * 2);
+///
+/// assert_eq!(vec, Ok(vec![0, 2, 4]));
+/// ```
+pub fn init_with_timeout<T: Send + 'static, F: FnMut(usize) -> T + Send + 'static>(length: usize, per_elem: Duration, mut elem: F) -> Result<Vec<T>, TimeoutError> {
+	let (send, recv) = mpsc::sync_channel(0);
+	let worker = thread::spawn(move || {
+		for i in 0..length {
+			if send.send(elem(i)).is_err() { break; }
+		}
+	});
+	
+	let mut value = Vec::with_capacity(length);
+	
+	for index in 0..length {
+		match recv.recv_timeout(per_elem) {
+			Ok(x) => value.push(x),
+			Err(RecvTimeoutError::Timeout) => return Err(TimeoutError { index }),
+			Err(RecvTimeoutError::Disconnected) => match worker.join() {
+				Err(payload) => resume_unwind(payload),
+				Ok(()) => unreachable!("worker finished without sending element {}", index),
+			},
+		}
+	}
+	
+	Ok(value)
+}
+
+/// Apply `elem` to each index in `0..length`, sending each element down `tx` in order instead of
+/// collecting them.
+///
+/// Stops at the first failed send, which happens once the receiver has been dropped, returning
+/// the element that couldn't be sent.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::mpsc::channel;
+/// use init_trait::init_to_sender;
+///
+/// let (tx, rx) = channel();
+/// init_to_sender(3, &tx, |i| i * 2).unwrap();
+/// drop(tx);
+///
+/// assert_eq!(rx.iter().collect::<Vec<_>>(), vec![0, 2, 4]);
+/// ```
+pub fn init_to_sender<T, F: FnMut(usize) -> T>(length: usize, tx: &Sender<T>, mut elem: F) -> Result<(), SendError<T>> {
+	for i in 0..length {
+		tx.send(elem(i))?;
+	}
+	
+	Ok(())
+}
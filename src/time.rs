@@ -0,0 +1,95 @@
+use core::time::Duration;
+
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use super::{Init, Vec};
+
+/// Initialise an exponential backoff schedule of `n` durations, starting at `base` and multiplying
+/// by `factor` each time.
+///
+/// Durations which would be too large to represent saturate at `Duration::MAX`.
+///
+/// # Panics
+///
+/// Panics if `factor` is negative or NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::time::Duration;
+/// use init_trait::init_backoff;
+///
+/// let schedule = init_backoff(Duration::from_millis(100), 2.0, 4);
+///
+/// assert_eq!(schedule, vec![
+///     Duration::from_millis(100),
+///     Duration::from_millis(200),
+///     Duration::from_millis(400),
+///     Duration::from_millis(800),
+/// ]);
+/// ```
+pub fn init_backoff(base: Duration, factor: f64, n: usize) -> Vec<Duration> {
+	init_backoff_capped(base, factor, Duration::MAX, n)
+}
+
+/// Initialise an exponential backoff schedule of `n` durations like `init_backoff`, but with each
+/// duration limited to at most `cap`.
+///
+/// # Panics
+///
+/// Panics if `factor` is negative or NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::time::Duration;
+/// use init_trait::init_backoff_capped;
+///
+/// let schedule = init_backoff_capped(Duration::from_secs(1), 3.0, Duration::from_secs(5), 4);
+///
+/// assert_eq!(schedule, vec![
+///     Duration::from_secs(1),
+///     Duration::from_secs(3),
+///     Duration::from_secs(5),
+///     Duration::from_secs(5),
+/// ]);
+/// ```
+pub fn init_backoff_capped(base: Duration, factor: f64, cap: Duration, n: usize) -> Vec<Duration> {
+	assert!(factor >= 0.0, "backoff factor must be non-negative, not {}", factor);
+	
+	let mut secs = base.as_secs_f64();
+	
+	Vec::init_with(n, |_| {
+		let value = Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX).min(cap);
+		secs *= factor;
+		value
+	})
+}
+
+/// Initialise a `Vec` of length `length` by applying `elem` to each index, along with how long
+/// each call to `elem` took.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_profiled_with;
+///
+/// let (values, durations) = init_profiled_with(3, |i| i * 2);
+///
+/// assert_eq!(values, vec![0, 2, 4]);
+/// assert_eq!(durations.len(), 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn init_profiled_with<T, F: FnMut(usize) -> T>(length: usize, mut elem: F) -> (Vec<T>, Vec<Duration>) {
+	let mut durations = Vec::with_capacity(length);
+	
+	let values = Vec::init_with(length, |i| {
+		let start = Instant::now();
+		let x = elem(i);
+		durations.push(start.elapsed());
+		x
+	});
+	
+	(values, durations)
+}
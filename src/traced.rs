@@ -0,0 +1,27 @@
+use tracing::{trace, trace_span};
+
+use super::{Init, Vec};
+
+/// Initialise a `Vec` of length `length` by applying `elem` to each index, with each call to
+/// `elem` inside its own `init_elem` span carrying the index.
+///
+/// An event is emitted inside the span once each element is built, all at the `TRACE` level.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_traced_with;
+///
+/// let value = init_traced_with(3, |i| i * 2);
+///
+/// assert_eq!(value, vec![0, 2, 4]);
+/// ```
+pub fn init_traced_with<T, F: FnMut(usize) -> T>(length: usize, mut elem: F) -> Vec<T> {
+	Vec::init_with(length, |i| {
+		let span = trace_span!("init_elem", index = i);
+		let _entered = span.enter();
+		let x = elem(i);
+		trace!("element initialised");
+		x
+	})
+}
@@ -0,0 +1,978 @@
+#[cfg(feature = "step_trait")]
+use core::iter::Step;
+use core::convert::TryInto;
+use core::fmt;
+use core::mem::size_of;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::{collections::{HashMap, hash_map::Entry}, rc::Rc};
+
+#[cfg(feature = "std")]
+use std::boxed::Box;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::boxed::Box;
+
+#[cfg(feature = "std")]
+use std::collections::TryReserveError;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::collections::TryReserveError;
+
+use super::{Init, Vec};
+use super::guard::{splitmix64, try_init_vec};
+
+/// An error from a build which was cancelled before it finished.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("initialisation was cancelled")
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Cancelled { }
+
+/// An error from fallibly initialising a `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InitError<E> {
+	/// The function initialising the elements returned an error.
+	Closure(E),
+	/// The memory for the elements couldn't be allocated.
+	Capacity(TryReserveError),
+}
+
+/// Converts an allocation failure, so that `?` can be used on `try_reserve` in a function
+/// returning `InitError`.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::InitError;
+///
+/// fn reserve(n: usize) -> Result<Vec<u64>, InitError<()>> {
+///     let mut vec = Vec::new();
+///     vec.try_reserve(n)?;
+///     Ok(vec)
+/// }
+///
+/// assert!(reserve(4).is_ok());
+/// assert!(matches!(reserve(usize::MAX), Err(InitError::Capacity(_))));
+/// ```
+impl<E> From<TryReserveError> for InitError<E> {
+	fn from(err: TryReserveError) -> Self {
+		InitError::Capacity(err)
+	}
+}
+
+impl<E: fmt::Display> fmt::Display for InitError<E> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			InitError::Closure(err) => write!(f, "initialising an element failed: {}", err),
+			InitError::Capacity(err) => write!(f, "couldn't allocate the elements: {}", err),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for InitError<E> {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			InitError::Closure(err) => Some(err),
+			InitError::Capacity(err) => Some(err),
+		}
+	}
+}
+
+/// Additional initialisers for `Vec`s which don't fit the general `Init` interface.
+pub trait VecInit<T>: Init<T, usize, usize> {
+	/// Initialise a `Vec` of length `2 * pairs` which alternates between elements from `left` and
+	/// `right`, i.e. `[left(0), right(0), left(1), right(1), ...]`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let stereo = Vec::init_interleave_with(3, |i| i as i32, |i| -(i as i32));
+	///
+	/// assert_eq!(stereo, vec![0, 0, 1, -1, 2, -2]);
+	/// ```
+	fn init_interleave_with<F: FnMut(usize) -> T, G: FnMut(usize) -> T>(pairs: usize, left: F, right: G) -> Self;
+	
+	/// Initialise a `Vec` by applying `elem` to each index and the elements built so far, until
+	/// it returns `None`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// // Fibonacci numbers below 50
+	/// let fib = Vec::init_until(|i, prev: &[u32]| match i {
+	///     0 | 1 => Some(1),
+	///     _ => Some(prev[i - 1] + prev[i - 2]).filter(|&x| x < 50),
+	/// });
+	///
+	/// assert_eq!(fib, vec![1, 1, 2, 3, 5, 8, 13, 21, 34]);
+	/// ```
+	fn init_until<F: FnMut(usize, &[T]) -> Option<T>>(elem: F) -> Self;
+	
+	/// Initialise a `Vec` of length `length` by applying `elem` to each index, folding each element
+	/// into the accumulator `init_acc` with `fold` as it's built.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let (vec, sum) = Vec::init_with_fold(4, 0, |i| i * 2, |sum, x| *sum += x);
+	///
+	/// assert_eq!(vec, vec![0, 2, 4, 6]);
+	/// assert_eq!(sum, 12);
+	/// ```
+	fn init_with_fold<F: FnMut(usize) -> T, A, G: FnMut(&mut A, &T)>(length: usize, init_acc: A, elem: F, fold: G) -> (Self, A);
+	
+	/// Initialise a `Vec` by applying `elem` to each index in `start..end`, for any index type
+	/// which implements `Step`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let letters = Vec::init_steps('a', 'e', |c| c.to_ascii_uppercase());
+	///
+	/// assert_eq!(letters, vec!['A', 'B', 'C', 'D']);
+	/// ```
+	#[cfg(feature = "step_trait")]
+	fn init_steps<S: Step, F: FnMut(S) -> T>(start: S, end: S, elem: F) -> Self;
+	
+	/// Initialise a `Vec` of length `length` by applying `elem` to each index, then check the whole
+	/// `Vec` with `validate`, dropping it and returning the error if it's invalid.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let is_sorted = |v: &[i32]| if v.windows(2).all(|w| w[0] <= w[1]) { Ok(()) } else { Err("not sorted") };
+	///
+	/// assert_eq!(Vec::init_validated_with(3, |i| i as i32, is_sorted), Ok(vec![0, 1, 2]));
+	/// assert_eq!(Vec::init_validated_with(3, |i| -(i as i32), is_sorted), Err("not sorted"));
+	/// ```
+	fn init_validated_with<F: FnMut(usize) -> T, V: FnOnce(&[T]) -> Result<(), E>, E>(length: usize, elem: F, validate: V) -> Result<Self, E>;
+	
+	/// Initialise a `Vec` of length `length` by applying `elem` to each index, also returning the
+	/// indices of the elements for which `is_sentinel` returned `true`.
+	///
+	/// This is useful for reporting which positions fell back to a placeholder value.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let readings = ["12", "x", "7", ""];
+	/// let (values, fallbacks) = Vec::init_tracking_sentinel(4, |i| readings[i].parse().unwrap_or(0), |&x| x == 0);
+	///
+	/// assert_eq!(values, vec![12, 0, 7, 0]);
+	/// assert_eq!(fallbacks, vec![1, 3]);
+	/// ```
+	fn init_tracking_sentinel<F: FnMut(usize) -> T, P: FnMut(&T) -> bool>(length: usize, elem: F, is_sentinel: P) -> (Self, Vec<usize>);
+	
+	/// Initialise a `Vec` of length `length` by applying `elem` to each index, using `n_threads`
+	/// scoped threads which each fill a contiguous stripe of the `Vec`.
+	///
+	/// A `n_threads` of `0` is treated as `1`. If `elem` panics on any thread, the other threads
+	/// are joined, every element built so far is dropped, and the panic is propagated.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let squares = Vec::threaded_init_with(4, 10, |i| i * i);
+	///
+	/// assert_eq!(squares, vec![0, 1, 4, 9, 16, 25, 36, 49, 64, 81]);
+	/// ```
+	#[cfg(feature = "std")]
+	fn threaded_init_with<F: Fn(usize) -> T + Sync>(n_threads: usize, length: usize, elem: F) -> Self where T: Send;
+	
+	/// Initialise a `Vec` with as many elements as fit in `max_bytes`, by applying `elem` to each
+	/// index.
+	///
+	/// Only the elements themselves are counted, not the `Vec`'s own metadata. For zero-sized types
+	/// the budget can't be used up, so an empty `Vec` is returned instead.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let samples = Vec::<u32>::init_within_bytes(10, |i| i as u32);
+	///
+	/// assert_eq!(samples, vec![0, 1]);
+	/// ```
+	fn init_within_bytes<F: FnMut(usize) -> T>(max_bytes: usize, elem: F) -> Self;
+	
+	/// Initialise a `Vec` of length `length` by applying `elem` to each index, calling `retry`
+	/// whenever it fails to decide whether to try that index again.
+	///
+	/// `retry` receives the error and the number of attempts made so far for that index, starting at
+	/// `1`. If it returns `false`, the elements built so far are dropped and the error is returned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let mut flaky = 0;
+	/// let vec = Vec::init_with_retry(3, |i| {
+	///     flaky += 1;
+	///     if flaky % 2 == 0 { Err("timed out") } else { Ok(i) }
+	/// }, |_, attempts| attempts < 3);
+	///
+	/// assert_eq!(vec, Ok(vec![0, 1, 2]));
+	/// ```
+	fn init_with_retry<F: FnMut(usize) -> Result<T, E>, R: FnMut(&E, u32) -> bool, E>(length: usize, elem: F, retry: R) -> Result<Self, E>;
+	
+	/// Initialise a `Vec` of length `length` by applying `elem` to each index, reporting progress to
+	/// `progress(done, total)` as it goes.
+	///
+	/// `progress` is called after every `max(length / 100, 1)` elements, so roughly once per percent,
+	/// and always finishes with a call where `done == total`, even if `length` is `0`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let mut reports = Vec::new();
+	/// let vec = Vec::init_with_progress(3, |i| i, |done, total| reports.push((done, total)));
+	///
+	/// assert_eq!(vec, vec![0, 1, 2]);
+	/// assert_eq!(reports, vec![(1, 3), (2, 3), (3, 3)]);
+	/// ```
+	fn init_with_progress<F: FnMut(usize) -> T, P: FnMut(usize, usize)>(length: usize, elem: F, progress: P) -> Self;
+	
+	/// Initialise a `Vec` of length `length` by applying `elem` to each index, checking `cancel`
+	/// before each element and giving up if it's set.
+	///
+	/// If the build is cancelled, the elements built so far are dropped and `Cancelled` is returned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use std::sync::atomic::AtomicBool;
+	/// use init_trait::{VecInit, Cancelled};
+	///
+	/// let running = AtomicBool::new(false);
+	/// let stopped = AtomicBool::new(true);
+	///
+	/// assert_eq!(Vec::init_cancellable_with(3, &running, |i| i), Ok(vec![0, 1, 2]));
+	/// assert_eq!(Vec::init_cancellable_with(3, &stopped, |i| i), Err(Cancelled));
+	/// ```
+	fn init_cancellable_with<F: FnMut(usize) -> T>(length: usize, cancel: &AtomicBool, elem: F) -> Result<Self, Cancelled>;
+	
+	/// Initialise a `Vec` by applying `elem` to each index in `0..length`, collapsing each run of
+	/// equal consecutive elements into its first element.
+	///
+	/// Duplicates which aren't next to each other are kept, as with `Vec::dedup`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let runs = Vec::init_dedup_consecutive_with(6, |i| i / 2);
+	///
+	/// assert_eq!(runs, vec![0, 1, 2]);
+	/// ```
+	fn init_dedup_consecutive_with<F: FnMut(usize) -> T>(length: usize, elem: F) -> Self where T: PartialEq;
+	
+	/// Initialise a `Vec` from the items of a fallible iterator, stopping at the first error.
+	///
+	/// Space is reserved for the iterator's lower size hint up front. If an item is an error, the
+	/// elements collected so far are dropped and the error is returned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let nums = Vec::<u8>::init_try_from_iter("1 2 3".split(' ').map(str::parse));
+	/// assert_eq!(nums, Ok(vec![1, 2, 3]));
+	///
+	/// let nums = Vec::<u8>::init_try_from_iter("1 x 3".split(' ').map(str::parse));
+	/// assert!(nums.is_err());
+	/// ```
+	fn init_try_from_iter<It: IntoIterator<Item = Result<T, E>>, E>(iter: It) -> Result<Self, E>;
+	
+	/// Initialise a `Vec` of length `length` by applying `elem` to each index along with the last
+	/// `K` elements produced, oldest first.
+	///
+	/// Before `K` elements have been produced, the window is padded at the front with the
+	/// remaining elements of `seed`. This suits recurrences such as IIR filters.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let fib = Vec::init_lookback_with(6, [0, 1], |_, &[a, b]| a + b);
+	///
+	/// assert_eq!(fib, vec![1, 2, 3, 5, 8, 13]);
+	/// ```
+	fn init_lookback_with<const K: usize, F: FnMut(usize, &[T; K]) -> T>(length: usize, seed: [T; K], elem: F) -> Self where T: Copy;
+	
+	/// Initialise a `Vec` by applying `elem` to each index in turn, where each element comes with a
+	/// weight, for as long as the total weight stays within `budget`.
+	///
+	/// The first element that would take the total over `budget` is dropped and not included.
+	/// This never stops if `elem` keeps returning a weight of zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let packed = Vec::init_budget_with(10, |i| (i, i as u64));
+	///
+	/// assert_eq!(packed, vec![0, 1, 2, 3, 4]);
+	/// ```
+	fn init_budget_with<F: FnMut(usize) -> (T, u64)>(budget: u64, elem: F) -> Self;
+	
+	/// Initialise a `Vec` of length `length`, taking each element from `cache` if its index is
+	/// already there and otherwise applying `elem` and storing a copy in `cache`.
+	///
+	/// With a warm cache, rebuilding only calls `elem` for the indices that are missing.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use std::collections::HashMap;
+	/// use init_trait::VecInit;
+	///
+	/// let mut cache = HashMap::new();
+	/// cache.insert(1, 100);
+	///
+	/// let value = Vec::init_cached_with(3, &mut cache, |i| i);
+	///
+	/// assert_eq!(value, vec![0, 100, 2]);
+	/// assert_eq!(cache.len(), 3);
+	/// ```
+	#[cfg(feature = "std")]
+	fn init_cached_with<F: FnMut(usize) -> T>(length: usize, cache: &mut HashMap<usize, T>, elem: F) -> Self where T: Clone;
+	
+	/// Initialise a `Vec` of `2 * half_width + 1` elements by applying `elem` to each signed index
+	/// in `-half_width..=half_width`, so that the element for index zero is in the middle.
+	///
+	/// # Panics
+	///
+	/// Panics if `half_width` is larger than `isize::MAX / 2`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let kernel = Vec::init_centered_with(2, |i| i * i);
+	///
+	/// assert_eq!(kernel, vec![4, 1, 0, 1, 4]);
+	/// ```
+	fn init_centered_with<F: FnMut(isize) -> T>(half_width: usize, elem: F) -> Self;
+	
+	/// Initialise a `Vec` of length `length` by applying `elem` to each index, then shuffle it
+	/// with a Fisher–Yates shuffle seeded by `seed`.
+	///
+	/// The same seed always gives the same order, on every platform. The shuffle uses a small
+	/// built-in generator (SplitMix64), so it is fine for fixtures but not for anything which
+	/// needs to be unpredictable.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use init_trait::VecInit;
+	///
+	/// let mut deck = Vec::init_shuffled_with(10, 42, |i| i);
+	/// assert_eq!(deck, Vec::init_shuffled_with(10, 42, |i| i));
+	///
+	/// deck.sort();
+	/// assert_eq!(deck, (0..10).collect::<Vec<_>>());
+	/// ```
+	fn init_shuffled_with<F: FnMut(usize) -> T>(length: usize, seed: u64, elem: F) -> Self;
+}
+
+impl<T> VecInit<T> for Vec<T> {
+	fn init_interleave_with<F: FnMut(usize) -> T, G: FnMut(usize) -> T>(pairs: usize, mut left: F, mut right: G) -> Self {
+		let length = pairs.checked_mul(2).expect("capacity overflow");
+		
+		Self::init_with(length, |i| if i % 2 == 0 { left(i / 2) } else { right(i / 2) })
+	}
+	
+	fn init_until<F: FnMut(usize, &[T]) -> Option<T>>(mut elem: F) -> Self {
+		let mut value = Vec::new();
+		
+		while let Some(x) = elem(value.len(), &value) {
+			value.push(x);
+		}
+		
+		value
+	}
+	
+	fn init_with_fold<F: FnMut(usize) -> T, A, G: FnMut(&mut A, &T)>(length: usize, init_acc: A, mut elem: F, mut fold: G) -> (Self, A) {
+		let mut acc = init_acc;
+		let value = Self::init_with(length, |i| {
+			let x = elem(i);
+			fold(&mut acc, &x);
+			x
+		});
+		
+		(value, acc)
+	}
+	
+	#[cfg(feature = "step_trait")]
+	fn init_steps<S: Step, F: FnMut(S) -> T>(start: S, end: S, elem: F) -> Self {
+		(start..end).map(elem).collect()
+	}
+	
+	fn init_validated_with<F: FnMut(usize) -> T, V: FnOnce(&[T]) -> Result<(), E>, E>(length: usize, elem: F, validate: V) -> Result<Self, E> {
+		let value = Self::init_with(length, elem);
+		validate(&value)?;
+		Ok(value)
+	}
+	
+	fn init_tracking_sentinel<F: FnMut(usize) -> T, P: FnMut(&T) -> bool>(length: usize, mut elem: F, mut is_sentinel: P) -> (Self, Vec<usize>) {
+		let mut sentinels = Vec::new();
+		let value = Self::init_with(length, |i| {
+			let x = elem(i);
+			if is_sentinel(&x) { sentinels.push(i); }
+			x
+		});
+		
+		(value, sentinels)
+	}
+	
+	#[cfg(feature = "std")]
+	fn threaded_init_with<F: Fn(usize) -> T + Sync>(n_threads: usize, length: usize, elem: F) -> Self where T: Send {
+		let mut value = Vec::with_capacity(length);
+		
+		// SAFETY: the `Vec` has capacity for `length` elements, and `fill_striped` initialises all of
+		// them or panics, leaving the length at `0`
+		unsafe {
+			super::thread::fill_striped(value.as_mut_ptr(), length, n_threads, &elem);
+			value.set_len(length);
+		}
+		
+		value
+	}
+	
+	fn init_within_bytes<F: FnMut(usize) -> T>(max_bytes: usize, elem: F) -> Self {
+		let length = max_bytes.checked_div(size_of::<T>()).unwrap_or(0);
+		
+		Self::init_with(length, elem)
+	}
+	
+	fn init_with_retry<F: FnMut(usize) -> Result<T, E>, R: FnMut(&E, u32) -> bool, E>(length: usize, mut elem: F, mut retry: R) -> Result<Self, E> {
+		try_init_vec(length, |i| {
+			let mut attempts = 1;
+			
+			loop {
+				match elem(i) {
+					Ok(x) => return Ok(x),
+					Err(e) if retry(&e, attempts) => attempts += 1,
+					Err(e) => return Err(e),
+				}
+			}
+		})
+	}
+	
+	fn init_with_progress<F: FnMut(usize) -> T, P: FnMut(usize, usize)>(length: usize, mut elem: F, mut progress: P) -> Self {
+		let step = (length / 100).max(1);
+		let value = Self::init_with(length, |i| {
+			let x = elem(i);
+			let done = i + 1;
+			if done % step == 0 && done != length { progress(done, length); }
+			x
+		});
+		
+		progress(length, length);
+		value
+	}
+	
+	fn init_cancellable_with<F: FnMut(usize) -> T>(length: usize, cancel: &AtomicBool, mut elem: F) -> Result<Self, Cancelled> {
+		try_init_vec(length, |i| if cancel.load(Ordering::Acquire) { Err(Cancelled) } else { Ok(elem(i)) })
+	}
+	
+	fn init_dedup_consecutive_with<F: FnMut(usize) -> T>(length: usize, mut elem: F) -> Self where T: PartialEq {
+		let mut value: Vec<T> = Vec::new();
+		
+		for i in 0..length {
+			let x = elem(i);
+			if value.last() != Some(&x) { value.push(x); }
+		}
+		
+		value
+	}
+	
+	fn init_try_from_iter<It: IntoIterator<Item = Result<T, E>>, E>(iter: It) -> Result<Self, E> {
+		let iter = iter.into_iter();
+		let mut value = Vec::with_capacity(iter.size_hint().0);
+		
+		for x in iter {
+			value.push(x?);
+		}
+		
+		Ok(value)
+	}
+	
+	fn init_lookback_with<const K: usize, F: FnMut(usize, &[T; K]) -> T>(length: usize, seed: [T; K], mut elem: F) -> Self where T: Copy {
+		let mut window = seed;
+		
+		Self::init_with(length, |i| {
+			let x = elem(i, &window);
+			if K > 0 {
+				window.copy_within(1.., 0);
+				window[K - 1] = x;
+			}
+			x
+		})
+	}
+	
+	fn init_budget_with<F: FnMut(usize) -> (T, u64)>(budget: u64, mut elem: F) -> Self {
+		let mut value = Vec::new();
+		let mut total = 0u64;
+		
+		loop {
+			let (x, weight) = elem(value.len());
+			match total.checked_add(weight) {
+				Some(t) if t <= budget => total = t,
+				_ => return value,
+			}
+			value.push(x);
+		}
+	}
+	
+	#[cfg(feature = "std")]
+	fn init_cached_with<F: FnMut(usize) -> T>(length: usize, cache: &mut HashMap<usize, T>, mut elem: F) -> Self where T: Clone {
+		Self::init_with(length, |i| cache.entry(i).or_insert_with(|| elem(i)).clone())
+	}
+	
+	fn init_centered_with<F: FnMut(isize) -> T>(half_width: usize, mut elem: F) -> Self {
+		assert!(half_width <= isize::MAX as usize / 2, "half width {} is too large", half_width);
+		
+		let offset = half_width as isize;
+		Self::init_with(2 * half_width + 1, |i| elem(i as isize - offset))
+	}
+	
+	fn init_shuffled_with<F: FnMut(usize) -> T>(length: usize, seed: u64, elem: F) -> Self {
+		let mut value = Self::init_with(length, elem);
+		let mut state = seed;
+		
+		for i in (1..length).rev() {
+			// Scale the random word into `0..=i`, widening so any length is handled
+			let j = ((splitmix64(&mut state) as u128 * (i as u128 + 1)) >> 64) as usize;
+			value.swap(i, j);
+		}
+		
+		value
+	}
+}
+
+/// Initialise two `Vec`s of length `length` in one pass by applying `elem` to each index and
+/// splitting the resulting pairs.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_pair_with;
+///
+/// let (positions, velocities) = init_pair_with(3, |i| (i as f32, -(i as f32)));
+///
+/// assert_eq!(positions, vec![0.0, 1.0, 2.0]);
+/// assert_eq!(velocities, vec![-0.0, -1.0, -2.0]);
+/// ```
+pub fn init_pair_with<A, B, F: FnMut(usize) -> (A, B)>(length: usize, mut elem: F) -> (Vec<A>, Vec<B>) {
+	let mut first = Vec::with_capacity(length);
+	let mut second = Vec::with_capacity(length);
+	
+	for i in 0..length {
+		let (a, b) = elem(i);
+		first.push(a);
+		second.push(b);
+	}
+	
+	(first, second)
+}
+
+/// Initialise a jagged `Vec` of `rows` rows, where row `r` has `row_len(r)` cells, by applying
+/// `elem` to each `[row, column]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_jagged_with;
+///
+/// let triangle = init_jagged_with(3, |r| r + 1, |[r, c]| r * 10 + c);
+///
+/// assert_eq!(triangle, vec![vec![0], vec![10, 11], vec![20, 21, 22]]);
+/// ```
+pub fn init_jagged_with<T, L: FnMut(usize) -> usize, F: FnMut([usize; 2]) -> T>(rows: usize, mut row_len: L, mut elem: F) -> Vec<Vec<T>> {
+	Vec::init_with(rows, |r| Vec::init_with(row_len(r), |c| elem([r, c])))
+}
+
+/// Initialise a `Vec` of `chunks` arrays of length `K` by applying `elem` to each global index
+/// `chunk * K + offset`.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_chunks;
+///
+/// let batches = init_chunks::<_, 3, _>(2, |i| i);
+///
+/// assert_eq!(batches, vec![[0, 1, 2], [3, 4, 5]]);
+/// ```
+pub fn init_chunks<T, const K: usize, F: FnMut(usize) -> T>(chunks: usize, mut elem: F) -> Vec<[T; K]> {
+	Vec::init_with(chunks, |chunk| <[T; K]>::init(|offset| elem(chunk * K + offset)))
+}
+
+/// Initialise a `Vec` by applying `elem` to each item of `data` along with its index, allocating
+/// up front for the number of items.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_zip_with;
+///
+/// let labels = init_zip_with(['a', 'b', 'c'].iter(), |i, c| format!("{}{}", i, c));
+///
+/// assert_eq!(labels, vec!["0a", "1b", "2c"]);
+/// ```
+pub fn init_zip_with<T, D, It: ExactSizeIterator<Item = D>, F: FnMut(usize, D) -> T>(data: It, mut elem: F) -> Vec<T> {
+	let mut value = Vec::with_capacity(data.len());
+	value.extend(data.enumerate().map(|(i, d)| elem(i, d)));
+	value
+}
+
+/// Initialise a `Vec` by splitting `bytes` into records of `REC` bytes and applying `elem` to each
+/// one in order.
+///
+/// Any trailing bytes which don't make up a whole record are ignored. Using this with a `REC` of
+/// zero fails to compile.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_decode_records;
+///
+/// let bytes = [1, 0, 2, 0, 3, 1];
+/// let values = init_decode_records(&bytes, |&rec: &[u8; 2]| u16::from_le_bytes(rec));
+///
+/// assert_eq!(values, vec![1, 2, 259]);
+/// ```
+///
+/// ```rust
+/// use init_trait::init_decode_records;
+///
+/// let values = init_decode_records(&[7, 0, 8], |&rec: &[u8; 2]| u16::from_le_bytes(rec));
+///
+/// assert_eq!(values, vec![7]);
+/// ```
+pub fn init_decode_records<T, const REC: usize, F: FnMut(&[u8; REC]) -> T>(bytes: &[u8], mut elem: F) -> Vec<T> {
+	const { assert!(REC > 0, "records must be at least one byte long") };
+	
+	let mut records = bytes.chunks_exact(REC);
+	Vec::init_with(bytes.len() / REC, |_| elem(records.next().unwrap().try_into().unwrap()))
+}
+
+/// Initialise a permutation of length `length` by applying `perm` to each index, together with its
+/// inverse, so that `inverse[forward[i]] == i`.
+///
+/// # Panics
+///
+/// Panics if `perm` returns a position outside `0..length`. In debug builds, also panics if `perm`
+/// returns the same position twice.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_with_inverse;
+///
+/// let (forward, inverse) = init_with_inverse(4, |i| (i + 1) % 4);
+///
+/// assert_eq!(forward, vec![1, 2, 3, 0]);
+/// assert_eq!(inverse, vec![3, 0, 1, 2]);
+/// ```
+pub fn init_with_inverse<F: FnMut(usize) -> usize>(length: usize, mut perm: F) -> (Vec<usize>, Vec<usize>) {
+	let mut inverse = Vec::init_with(length, |_| usize::MAX);
+	let forward = Vec::init_with(length, |i| {
+		let p = perm(i);
+		assert!(p < length, "permutation maps index {} to {}, which is out of range for length {}", i, p, length);
+		debug_assert!(inverse[p] == usize::MAX, "permutation maps index {} to {}, which is already used", i, p);
+		inverse[p] = i;
+		p
+	});
+	
+	(forward, inverse)
+}
+
+/// Split `src` into `channels` strided `Vec`s, where channel `c` holds `src[c]`,
+/// `src[c + channels]`, and so on.
+///
+/// This is the inverse of `VecInit::init_interleave_with` for two channels. If the length of
+/// `src` isn't a multiple of `channels`, the first channels get one extra element.
+///
+/// # Panics
+///
+/// Panics if `channels` is `0`.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_deinterleave;
+///
+/// let stereo = ["L0", "R0", "L1", "R1"];
+///
+/// assert_eq!(init_deinterleave(&stereo, 2), vec![vec!["L0", "L1"], vec!["R0", "R1"]]);
+/// ```
+pub fn init_deinterleave<T: Clone>(src: &[T], channels: usize) -> Vec<Vec<T>> {
+	assert!(channels > 0, "can't deinterleave into 0 channels");
+	
+	Vec::init_with(channels, |c| src.iter().skip(c).step_by(channels).cloned().collect())
+}
+
+/// Initialise a flat `Vec` for a tensor with runtime dimensions `shape` by applying `elem` to the
+/// coordinates of each position, in row-major order.
+///
+/// The length is the product of `shape`, so an empty `shape` gives a single element with empty
+/// coordinates.
+///
+/// # Panics
+///
+/// Panics if the product of `shape` overflows `usize`.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_ragged;
+///
+/// let flat = init_ragged(&[2, 2], |xy| xy.to_vec());
+///
+/// assert_eq!(flat, vec![vec![0, 0], vec![0, 1], vec![1, 0], vec![1, 1]]);
+/// ```
+pub fn init_ragged<T, F: FnMut(&[usize]) -> T>(shape: &[usize], mut elem: F) -> Vec<T> {
+	let length = shape.iter().try_fold(1usize, |n, &d| n.checked_mul(d)).expect("capacity overflow");
+	let mut coords = Vec::init_with(shape.len(), |_| 0);
+	
+	Vec::init_with(length, |_| {
+		let x = elem(&coords);
+		
+		for (c, &d) in coords.iter_mut().zip(shape).rev() {
+			*c += 1;
+			if *c < d { break; }
+			*c = 0;
+		}
+		
+		x
+	})
+}
+
+/// Initialise a boxed slice of length `length` by applying `elem` to each index, then leak it as a
+/// raw pointer and length, for handing ownership across an FFI boundary.
+///
+/// The slice must eventually be passed back to `reclaim` to be dropped, or it's leaked.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::{init_boxed_raw, reclaim};
+///
+/// let (ptr, len) = init_boxed_raw(3, |i| i as u32 * 2);
+/// // SAFETY: `ptr` is valid for reading `len` elements until it's reclaimed
+/// assert_eq!(unsafe { *ptr.add(2) }, 4);
+///
+/// // SAFETY: `ptr` and `len` came from `init_boxed_raw` and haven't been reclaimed yet
+/// let slice = unsafe { reclaim(ptr, len) };
+/// assert_eq!(*slice, [0, 2, 4]);
+/// ```
+pub fn init_boxed_raw<T, F: FnMut(usize) -> T>(length: usize, elem: F) -> (*mut T, usize) {
+	let slice = Box::into_raw(Vec::init_with(length, elem).into_boxed_slice());
+	
+	(slice as *mut T, length)
+}
+
+/// Take back ownership of a boxed slice leaked by `init_boxed_raw`, which is dropped when the
+/// returned box is.
+///
+/// # Safety
+///
+/// `ptr` and `len` must be exactly the values returned by a call to `init_boxed_raw` with the same
+/// `T`, and that slice must not have been reclaimed already. The elements must still be
+/// initialised, though they may have been modified in place.
+pub unsafe fn reclaim<T>(ptr: *mut T, len: usize) -> Box<[T]> {
+	Box::from_raw(core::ptr::slice_from_raw_parts_mut(ptr, len))
+}
+
+/// Initialise a byte buffer by calling `encode` for each index in `0..count`, letting it append any
+/// number of bytes for that element.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_encode;
+///
+/// let words = ["hi", "there"];
+/// let encoded = init_encode(words.len(), |i, buf| {
+///     buf.push(words[i].len() as u8);
+///     buf.extend_from_slice(words[i].as_bytes());
+/// });
+///
+/// assert_eq!(encoded, b"\x02hi\x05there");
+/// ```
+pub fn init_encode<F: FnMut(usize, &mut Vec<u8>)>(count: usize, mut encode: F) -> Vec<u8> {
+	let mut value = Vec::new();
+	
+	for i in 0..count {
+		encode(i, &mut value);
+	}
+	
+	value
+}
+
+/// Initialise a `Vec` by applying `elem` to each index in `0..count` and flattening the
+/// resulting iterators in order.
+///
+/// Each index may produce any number of elements, so the length of the result is the total
+/// number produced.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_flat_map;
+///
+/// let expanded = init_flat_map(3, |i| vec![i; i]);
+///
+/// assert_eq!(expanded, vec![1, 2, 2]);
+/// ```
+pub fn init_flat_map<T, I: IntoIterator<Item = T>, F: FnMut(usize) -> I>(count: usize, mut elem: F) -> Vec<T> {
+	let mut value = Vec::new();
+	
+	for i in 0..count {
+		value.extend(elem(i));
+	}
+	
+	value
+}
+
+/// Initialise a `Vec` by concatenating segments, each given as a length and a generator.
+///
+/// Each generator is applied to the indices local to its own segment, starting from zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_concat;
+///
+/// let header = |_| 0xff;
+/// let body = |i| i as u8;
+/// let packet = init_concat(&[(2, &header), (3, &body)]);
+///
+/// assert_eq!(packet, vec![0xff, 0xff, 0, 1, 2]);
+/// ```
+pub fn init_concat<T>(segments: &[(usize, &dyn Fn(usize) -> T)]) -> Vec<T> {
+	let length = segments.iter().map(|&(len, _)| len).sum();
+	let mut value = Vec::with_capacity(length);
+	
+	for &(len, elem) in segments {
+		value.extend((0..len).map(elem));
+	}
+	
+	value
+}
+
+/// Initialise a `Vec` of weights of length `length` by applying `elem` to each index, then divide
+/// them all by their sum so that they add up to `1.0`.
+///
+/// If the weights sum to zero the result is all zeros.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_normalised_with;
+///
+/// let probs = init_normalised_with(4, |i| (i + 1) as f64);
+///
+/// assert_eq!(probs, vec![0.1, 0.2, 0.3, 0.4]);
+/// ```
+pub fn init_normalised_with<F: FnMut(usize) -> f64>(length: usize, elem: F) -> Vec<f64> {
+	let mut value = Vec::init_with(length, elem);
+	let sum: f64 = value.iter().sum();
+	
+	if sum == 0.0 {
+		value.iter_mut().for_each(|x| *x = 0.0);
+	} else {
+		value.iter_mut().for_each(|x| *x /= sum);
+	}
+	
+	value
+}
+
+/// Initialise a `Vec` of length `length` by applying `elem` to each index, together with a map from
+/// each distinct element to the index where it first appears.
+///
+/// # Examples
+///
+/// ```rust
+/// use init_trait::init_with_index;
+///
+/// let (tokens, first) = init_with_index(4, |i| ["a", "b", "a", "c"][i]);
+///
+/// assert_eq!(tokens, vec!["a", "b", "a", "c"]);
+/// assert_eq!(first["a"], 0);
+/// assert_eq!(first["c"], 3);
+/// ```
+#[cfg(feature = "std")]
+pub fn init_with_index<T: Eq + Hash + Clone, F: FnMut(usize) -> T>(length: usize, mut elem: F) -> (Vec<T>, HashMap<T, usize>) {
+	let mut index = HashMap::new();
+	let value = Vec::init_with(length, |i| {
+		let x = elem(i);
+		index.entry(x.clone()).or_insert(i);
+		x
+	});
+	
+	(value, index)
+}
+
+/// Initialise a `Vec` of length `length` which shares one `Rc` between all indices with the same
+/// `key(i)`, calling `build` only once for each distinct key.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use init_trait::init_memoised;
+///
+/// let labels = init_memoised(4, |i| i % 2, |k| format!("group {}", k));
+///
+/// assert_eq!(*labels[3], "group 1");
+/// assert!(Rc::ptr_eq(&labels[0], &labels[2]));
+/// ```
+#[cfg(feature = "std")]
+pub fn init_memoised<K: Eq + Hash, T, F: FnMut(usize) -> K, B: FnMut(&K) -> T>(length: usize, mut key: F, mut build: B) -> Vec<Rc<T>> {
+	let mut built = HashMap::new();
+	
+	Vec::init_with(length, |i| match built.entry(key(i)) {
+		Entry::Occupied(entry) => Rc::clone(entry.get()),
+		Entry::Vacant(entry) => {
+			let value = Rc::new(build(entry.key()));
+			Rc::clone(entry.insert(value))
+		},
+	})
+}
@@ -0,0 +1,29 @@
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use init_trait::{AffineInit, Init};
+
+#[test]
+fn init_affine_matches_init_with() {
+	assert_eq!(Vec::<usize>::init_affine(100, 3, 7), Vec::init_with(100, |i| 3 * i + 7));
+	assert_eq!(Vec::<i32>::init_affine(13, -2, 5), Vec::init_with(13, |i| -2 * i as i32 + 5));
+}
+
+#[test]
+fn init_affine_wide_integers() {
+	assert_eq!(Vec::<u128>::init_affine(10, 1 << 100, 3), Vec::init_with(10, |i| ((i as u128) << 100) + 3));
+	assert_eq!(Vec::<i128>::init_affine(10, -5, 2), Vec::init_with(10, |i| -5 * i as i128 + 2));
+}
+
+#[test]
+fn init_affine_floats() {
+	assert_eq!(Vec::<f64>::init_affine(21, 0.25, -1.0), Vec::init_with(21, |i| 0.25 * i as f64 - 1.0));
+}
+
+#[test]
+fn init_affine_wraps() {
+	let bytes = Vec::<u8>::init_affine(300, 1, 250);
+	assert_eq!(bytes[5], 255);
+	assert_eq!(bytes[6], 0);
+	assert_eq!(bytes[299], ((299 + 250) % 256) as u8);
+	assert!(Vec::<u8>::init_affine(0, 1, 1).is_empty());
+}
@@ -0,0 +1,510 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use init_trait::{Init, ArrayInit, LengthError, init_options_with, count_filled, init_mask, count_set, uninit_array, assume_init_array, init_const_permuted, init_from_const_slice};
+
+use common::Counted;
+
+#[test]
+fn from_fn_exact_pure() {
+	let arr = <[usize; 10]>::from_fn_exact(|i| i * 2);
+	assert_eq!(arr, [0, 2, 4, 6, 8, 10, 12, 14, 16, 18]);
+}
+
+#[test]
+fn from_fn_exact_empty() {
+	let mut calls = 0;
+	let arr = <[usize; 0]>::from_fn_exact(|_| { calls += 1; 0 });
+	assert_eq!(arr, []);
+	assert_eq!(calls, 0);
+}
+
+#[cfg(all(feature = "debug_checks", debug_assertions))]
+#[test]
+#[should_panic(expected = "returned a different value")]
+fn from_fn_exact_stateful() {
+	let mut calls = 0;
+	<[usize; 10]>::from_fn_exact(|_| { calls += 1; calls });
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_striped() {
+	let arr = <[usize; 1000]>::init_striped_with(4, |i| i * 3 + 1);
+	let seq = <[usize; 1000]>::init(|i| i * 3 + 1);
+	assert_eq!(arr[..], seq[..]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_striped_thread_counts() {
+	assert_eq!(<[usize; 5]>::init_striped_with(0, |i| i), [0, 1, 2, 3, 4]);
+	assert_eq!(<[usize; 5]>::init_striped_with(1, |i| i), [0, 1, 2, 3, 4]);
+	assert_eq!(<[usize; 5]>::init_striped_with(100, |i| i), [0, 1, 2, 3, 4]);
+	assert_eq!(<[usize; 0]>::init_striped_with(4, |_| panic!("Shouldn't call init function")), []);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_striped_panic_drops_all() {
+	use std::panic::catch_unwind;
+	
+	let built = AtomicUsize::new(0);
+	let drops = AtomicUsize::new(0);
+	let res = catch_unwind(|| {
+		<[Counted; 100]>::init_striped_with(4, |i| {
+			if i == 60 { panic!("Init function failed"); }
+			built.fetch_add(1, Ordering::SeqCst);
+			Counted(&drops)
+		})
+	});
+	assert!(res.is_err());
+	assert_eq!(drops.load(Ordering::SeqCst), built.load(Ordering::SeqCst));
+	assert_eq!(built.load(Ordering::SeqCst), 100 - 15);
+}
+
+#[test]
+fn init_options() {
+	let slots: [Option<char>; 3] = init_options_with(|i| if i == 1 { None } else { Some((b'a' + i as u8) as char) });
+	assert_eq!(slots, [Some('a'), None, Some('c')]);
+	assert_eq!(count_filled(&slots), 2);
+}
+
+#[test]
+fn count_filled_edges() {
+	assert_eq!(count_filled::<usize>(&[]), 0);
+	assert_eq!(count_filled(&[None::<usize>; 4]), 0);
+	assert_eq!(count_filled(&[Some(1), Some(2)]), 2);
+}
+
+#[test]
+fn init_partial() {
+	let (len, mut buf) = <[String; 5]>::init_partial(|i| if i < 3 { Some(i.to_string()) } else { None });
+	assert_eq!(len, 3);
+	let filled: Vec<String> = buf[..len].iter_mut().map(|slot| unsafe { slot.assume_init_read() }).collect();
+	assert_eq!(filled, vec!["0", "1", "2"]);
+}
+
+#[test]
+fn init_partial_stops_at_none() {
+	let mut calls = 0;
+	let (len, _) = <[usize; 5]>::init_partial(|i| { calls += 1; if i == 1 { None } else { Some(i) } });
+	assert_eq!(len, 1);
+	assert_eq!(calls, 2);
+}
+
+#[test]
+fn init_partial_full() {
+	let mut calls = 0;
+	let (len, buf) = <[usize; 4]>::init_partial(|i| { calls += 1; Some(i * 2) });
+	assert_eq!(len, 4);
+	assert_eq!(calls, 4);
+	assert_eq!(unsafe { buf[3].assume_init() }, 6);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn init_map_vec() {
+	let arr = <[(usize, String); 3]>::init_map_vec(vec!["a", "b", "c"], |i, s| (i, s.repeat(i + 1)));
+	assert_eq!(arr, Ok([(0, "a".to_string()), (1, "bb".to_string()), (2, "ccc".to_string())]));
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn init_map_vec_wrong_length() {
+	let short = <[usize; 3]>::init_map_vec(vec![1, 2], |_, x| x);
+	assert_eq!(short, Err(LengthError { expected: 3, actual: 2 }));
+	let long = <[usize; 3]>::init_map_vec(vec![1, 2, 3, 4], |_, _| panic!("Shouldn't call init function"));
+	assert_eq!(long, Err(LengthError { expected: 3, actual: 4 }));
+	assert_eq!(long.unwrap_err().to_string(), "expected 3 elements but found 4");
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn init_to_array_matching() {
+	let arr = <[String; 4]>::init_to_array(4, |i| i.to_string());
+	assert_eq!(arr, Ok(["0".to_string(), "1".to_string(), "2".to_string(), "3".to_string()]));
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn init_to_array_mismatching() {
+	assert_eq!(<[usize; 4]>::init_to_array(2, |i| i * 3), Err(vec![0, 3]));
+	assert_eq!(<[usize; 2]>::init_to_array(3, |i| i * 3), Err(vec![0, 3, 6]));
+	assert_eq!(<[usize; 0]>::init_to_array(0, |_| panic!("Shouldn't call init function")), Ok([]));
+}
+
+#[test]
+fn init_permuted_reversal() {
+	let mut calls = Vec::new();
+	let arr = <[char; 5]>::init_permuted_with(|i| 4 - i, |i| { calls.push(i); (b'a' + i as u8) as char });
+	assert_eq!(arr, ['e', 'd', 'c', 'b', 'a']);
+	assert_eq!(calls, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn init_permuted_rotation() {
+	let arr = <[usize; 6]>::init_permuted_with(|i| (i + 2) % 6, |i| i);
+	assert_eq!(arr, [4, 5, 0, 1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "already used")]
+fn init_permuted_not_bijective() {
+	<[usize; 4]>::init_permuted_with(|i| i / 2, |i| i);
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn init_permuted_out_of_range() {
+	<[usize; 4]>::init_permuted_with(|i| i + 1, |i| i);
+}
+
+#[test]
+fn init_control_breaks_at_index() {
+	use std::ops::ControlFlow;
+	
+	let drops = AtomicUsize::new(0);
+	let res = <[Counted; 5]>::init_control_with(|i| if i == 2 { ControlFlow::Break("stopped") } else { ControlFlow::Continue(Counted(&drops)) });
+	assert!(matches!(res, ControlFlow::Break("stopped")));
+	assert_eq!(drops.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn init_control_continues() {
+	use std::ops::ControlFlow;
+	
+	let res = <[usize; 4]>::init_control_with::<(), _>(|i| ControlFlow::Continue(i + 1));
+	assert_eq!(res, ControlFlow::Continue([1, 2, 3, 4]));
+}
+
+#[test]
+fn init_mask_primes() {
+	let primes: [bool; 20] = init_mask(|i| i >= 2 && (2..i).all(|d| i % d != 0));
+	assert!(primes[2] && primes[3] && primes[19]);
+	assert!(!primes[0] && !primes[1] && !primes[9]);
+	assert_eq!(count_set(&primes), 8);
+}
+
+#[test]
+fn count_set_edges() {
+	assert_eq!(count_set(&[]), 0);
+	assert_eq!(count_set(&[false; 3]), 0);
+	assert_eq!(count_set(&[true; 3]), 3);
+}
+
+#[test]
+fn init_from_slice_padded_shorter() {
+	let arr = <[usize; 6]>::init_from_slice_padded(&[7, 8], |i| i * 100);
+	assert_eq!(arr, [7, 8, 200, 300, 400, 500]);
+}
+
+#[test]
+fn init_from_slice_padded_equal() {
+	let arr = <[String; 2]>::init_from_slice_padded(&["a".to_string(), "b".to_string()], |_| panic!("Shouldn't call pad function"));
+	assert_eq!(arr, ["a", "b"]);
+}
+
+#[test]
+fn init_from_slice_padded_longer() {
+	let arr = <[char; 3]>::init_from_slice_padded(&['x', 'y', 'z', 'w'], |_| panic!("Shouldn't call pad function"));
+	assert_eq!(arr, ['x', 'y', 'z']);
+}
+
+#[test]
+fn init_strided_positions() {
+	let mut calls = Vec::new();
+	let arr = <[Option<usize>; 10]>::init_strided_with(3, None, |i| { calls.push(i); Some(i) });
+	assert_eq!(calls, vec![0, 3, 6, 9]);
+	assert_eq!(arr, [Some(0), None, None, Some(3), None, None, Some(6), None, None, Some(9)]);
+}
+
+#[test]
+fn init_strided_unit_stride() {
+	assert_eq!(<[usize; 4]>::init_strided_with(1, 99, |i| i), [0, 1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "stride")]
+fn init_strided_zero_stride() {
+	<[usize; 4]>::init_strided_with(0, 0, |i| i);
+}
+
+#[test]
+fn uninit_array_filled_by_pointer() {
+	unsafe extern "C" fn fill(out: *mut u32, len: usize) {
+		for i in 0..len {
+			out.add(i).write((i as u32 + 1) * 11);
+		}
+	}
+	
+	let mut buf = uninit_array::<u32, 5>();
+	// SAFETY: `fill` writes exactly `buf.len()` elements
+	let arr = unsafe {
+		fill(buf.as_mut_ptr() as *mut u32, buf.len());
+		assume_init_array(buf)
+	};
+	assert_eq!(arr, [11, 22, 33, 44, 55]);
+}
+
+#[test]
+fn assume_init_array_owned() {
+	let mut buf = uninit_array::<String, 2>();
+	buf[0].write("left".to_string());
+	buf[1].write("right".to_string());
+	// SAFETY: both elements were written above
+	let arr = unsafe { assume_init_array(buf) };
+	assert_eq!(arr, ["left", "right"]);
+}
+
+#[test]
+fn init_iter_then_tail_indices() {
+	let mut calls = Vec::new();
+	let arr = <[usize; 5]>::init_iter_then([10, 11].iter().copied(), |i| { calls.push(i); i });
+	assert_eq!(arr, [10, 11, 2, 3, 4]);
+	assert_eq!(calls, vec![2, 3, 4]);
+}
+
+#[test]
+fn init_iter_then_long_iterator() {
+	let mut iter = 0..10;
+	let arr = <[usize; 3]>::init_iter_then(iter.by_ref(), |_| panic!("Shouldn't call init function"));
+	assert_eq!(arr, [0, 1, 2]);
+	assert_eq!(iter.next(), Some(3));
+}
+
+#[test]
+fn init_tee_sums_during_construction() {
+	let mut sum = 0;
+	let mut seen = Vec::new();
+	let arr = <[usize; 5]>::init_tee_with(|i| i * 3, |i, &x| { sum += x; seen.push(i); });
+	assert_eq!(arr, [0, 3, 6, 9, 12]);
+	assert_eq!(sum, 30);
+	assert_eq!(seen, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn init_tee_observes_before_panic() {
+	use std::panic::{catch_unwind, AssertUnwindSafe};
+	
+	let mut observed = 0;
+	let res = catch_unwind(AssertUnwindSafe(|| <[String; 4]>::init_tee_with(|i| {
+		if i == 2 { panic!("Init function failed"); }
+		i.to_string()
+	}, |_, _| observed += 1)));
+	assert!(res.is_err());
+	assert_eq!(observed, 2);
+}
+
+#[test]
+fn init_offset_global_index() {
+	let mut seen = Vec::new();
+	let tile = <[usize; 4]>::init_offset_with(100, |i| { seen.push(i); i + 1 });
+	assert_eq!(tile[0], 101);
+	assert_eq!(tile, [101, 102, 103, 104]);
+	assert_eq!(seen, vec![100, 101, 102, 103]);
+}
+
+#[test]
+fn init_offset_zero() {
+	assert_eq!(<[usize; 3]>::init_offset_with(0, |i| i), [0, 1, 2]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_unique_array_ok() {
+	assert_eq!(<[usize; 5]>::init_unique_array_with(|i| i * i), Ok([0, 1, 4, 9, 16]));
+	assert_eq!(<[usize; 0]>::init_unique_array_with(|i| i), Ok([]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_unique_array_collision() {
+	assert_eq!(<[usize; 5]>::init_unique_array_with(|i| [7, 1, 2, 1, 7][i]), Err((1, 3)));
+	assert_eq!(<[usize; 4]>::init_unique_array_with(|_| 0), Err((0, 1)));
+}
+
+struct Config {
+	base: i32,
+	step: i32,
+	negate: bool,
+}
+
+#[test]
+fn init_with_context_config() {
+	let config = Config { base: 5, step: 3, negate: true };
+	let arr = <[i32; 4]>::init_with_context(&config, |i, c| {
+		let x = c.base + c.step * i as i32;
+		if c.negate { -x } else { x }
+	});
+	assert_eq!(arr, [-5, -8, -11, -14]);
+}
+
+#[test]
+fn init_with_context_nested() {
+	let config = Config { base: 1, step: 10, negate: false };
+	let grid = <[[i32; 3]; 2]>::init_with_context(&config, |r, c| {
+		<[i32; 3]>::init_with_context(c, |col, c| c.base + c.step * r as i32 + col as i32)
+	});
+	assert_eq!(grid, [[1, 2, 3], [11, 12, 13]]);
+}
+
+const REVERSED: [u8; 5] = init_const_permuted([1, 2, 3, 4, 5], [4, 3, 2, 1, 0]);
+const ROTATED: [char; 4] = init_const_permuted(['a', 'b', 'c', 'd'], [1, 2, 3, 0]);
+
+#[test]
+fn init_const_permuted_in_const() {
+	assert_eq!(REVERSED, [5, 4, 3, 2, 1]);
+	assert_eq!(ROTATED, ['b', 'c', 'd', 'a']);
+	assert_eq!(init_const_permuted([7, 8], [0, 1]), [7, 8]);
+}
+
+#[test]
+#[should_panic(expected = "permutation index repeated")]
+fn init_const_permuted_not_bijective() {
+	init_const_permuted([1, 2, 3], [0, 2, 0]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_from_env_overrides() {
+	std::env::set_var("INIT_TRAIT_TEST_ENV_0", "7");
+	std::env::set_var("INIT_TRAIT_TEST_ENV_2", "-3");
+	let arr = <[i32; 4]>::init_from_env("INIT_TRAIT_TEST_ENV", |s| s.parse().ok(), |i| i as i32 * 100);
+	assert_eq!(arr, [7, 100, -3, 300]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_from_env_unparseable() {
+	std::env::set_var("INIT_TRAIT_TEST_BAD_1", "not a number");
+	let mut fallbacks = Vec::new();
+	let arr = <[u8; 2]>::init_from_env("INIT_TRAIT_TEST_BAD", |s| s.parse().ok(), |i| { fallbacks.push(i); 0 });
+	assert_eq!(arr, [0, 0]);
+	assert_eq!(fallbacks, vec![0, 1]);
+}
+
+fn average_neighbours(i: usize, prev: &[f64]) -> f64 {
+	if i == 0 || i == prev.len() - 1 {
+		prev[i]
+	} else {
+		(prev[i - 1] + prev[i + 1]) / 2.0
+	}
+}
+
+#[test]
+fn init_relax_converges() {
+	// With fixed ends the averaging update converges to a straight line between them
+	let arr = <[f64; 5]>::init_relax([0.0, 9.0, -3.0, 7.0, 4.0], 200, average_neighbours);
+	for (i, x) in arr.iter().enumerate() {
+		assert!((x - i as f64).abs() < 1e-9, "{} at {}", x, i);
+	}
+}
+
+#[test]
+fn init_relax_uses_previous_sweep() {
+	assert_eq!(<[f64; 3]>::init_relax([1.0, 2.0, 3.0], 0, average_neighbours), [1.0, 2.0, 3.0]);
+	
+	// A Gauss-Seidel update would see the new value of cell 1 when computing cell 2
+	let arr = <[i32; 3]>::init_relax([1, 0, 0], 1, |i, prev| if i == 0 { prev[0] } else { prev[i - 1] });
+	assert_eq!(arr, [1, 1, 0]);
+}
+
+#[test]
+fn init_phased_split() {
+	let arr = <[(char, usize); 5]>::init_phased_with(2, |i| ('f', i), |i| ('s', i));
+	assert_eq!(arr, [('f', 0), ('f', 1), ('s', 2), ('s', 3), ('s', 4)]);
+}
+
+#[test]
+fn init_phased_split_out_of_range() {
+	assert_eq!(<[char; 3]>::init_phased_with(0, |_| 'f', |_| 's'), ['s'; 3]);
+	assert_eq!(<[char; 3]>::init_phased_with(10, |_| 'f', |_| 's'), ['f'; 3]);
+}
+
+#[test]
+fn init_monotonic_non_decreasing() {
+	assert_eq!(<[u8; 5]>::init_monotonic_with(|i| (i / 2) as u8), [0, 0, 1, 1, 2]);
+	assert_eq!(<[f64; 3]>::init_monotonic_with(|i| i as f64 * 0.5), [0.0, 0.5, 1.0]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "element at index 3")]
+fn init_monotonic_decreasing() {
+	<[i32; 5]>::init_monotonic_with(|i| [1, 2, 5, 4, 6][i]);
+}
+
+#[test]
+fn init_with_extents_non_monotonic() {
+	let (arr, extents) = <[i32; 6]>::init_with_extents(|i| [4, 9, -2, 9, 0, -2][i]);
+	assert_eq!(arr, [4, 9, -2, 9, 0, -2]);
+	assert_eq!(extents, Some((-2, 9)));
+	
+	let (_, extents) = <[String; 3]>::init_with_extents(|i| ["pear", "apple", "quince"][i].to_string());
+	assert_eq!(extents, Some(("apple".to_string(), "quince".to_string())));
+}
+
+#[test]
+fn init_with_extents_small() {
+	let (arr, extents) = <[u8; 0]>::init_with_extents(|i| i as u8);
+	assert_eq!(arr, [] as [u8; 0]);
+	assert_eq!(extents, None);
+	
+	assert_eq!(<[u8; 1]>::init_with_extents(|_| 5), ([5], Some((5, 5))));
+}
+
+const TABLE: &[u32] = &[2, 3, 5, 7];
+const PRIMES: [u32; 4] = init_from_const_slice(TABLE);
+const NONE: [u32; 0] = init_from_const_slice(&[]);
+
+#[test]
+fn init_from_const_slice_in_const() {
+	assert_eq!(PRIMES, [2, 3, 5, 7]);
+	assert_eq!(NONE, [] as [u32; 0]);
+	assert_eq!(init_from_const_slice::<_, 2>(&TABLE[1..3]), [3, 5]);
+}
+
+#[test]
+#[should_panic(expected = "slice length doesn't match")]
+fn init_from_const_slice_runtime_mismatch() {
+	let src = vec![1, 2, 3];
+	init_from_const_slice::<i32, 2>(&src);
+}
+
+#[test]
+fn init_radix_digits_base_2() {
+	let digits = <[[usize; 3]; 8]>::init_radix_digits_with::<2, 3, _>(|d| d);
+	assert_eq!(digits, [
+		[0, 0, 0], [0, 0, 1], [0, 1, 0], [0, 1, 1],
+		[1, 0, 0], [1, 0, 1], [1, 1, 0], [1, 1, 1],
+	]);
+}
+
+#[test]
+fn init_radix_digits_round_trip() {
+	let values = <[usize; 27]>::init_radix_digits_with::<3, 3, _>(|[a, b, c]| a * 9 + b * 3 + c);
+	assert_eq!(values, <[usize; 27]>::init(|i| i));
+	
+	assert_eq!(<[usize; 1]>::init_radix_digits_with::<10, 0, _>(|d: [usize; 0]| d.len()), [0]);
+}
+
+#[test]
+fn init_periodic_markers() {
+	let arr = <[(bool, usize); 8]>::init_periodic_with(3, |i| (false, i), |i| (true, i));
+	let special: Vec<usize> = arr.iter().filter(|&&(s, _)| s).map(|&(_, i)| i).collect();
+	assert_eq!(special, vec![0, 3, 6]);
+	assert_eq!(arr[4], (false, 4));
+}
+
+#[test]
+fn init_periodic_period_one() {
+	assert_eq!(<[u8; 3]>::init_periodic_with(1, |_| 0, |_| 1), [1, 1, 1]);
+}
+
+#[test]
+#[should_panic(expected = "period must be greater than 0")]
+fn init_periodic_zero_period() {
+	<[u8; 3]>::init_periodic_with(0, |_| 0, |_| 1);
+}
@@ -0,0 +1,150 @@
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::{BuildHasherDefault, Hasher};
+
+use init_trait::{Init, init_deque_rotated_with, init_bounded_deque_with, init_sorted_map, init_min_heap};
+
+#[test]
+fn init_deque() {
+	let deque = VecDeque::init_with(5, |i| i * 10);
+	assert_eq!(deque, [0, 10, 20, 30, 40]);
+}
+
+#[test]
+fn init_deque_rotated() {
+	let deque = init_deque_rotated_with(5, 2, |i| i);
+	assert_eq!(deque.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 0, 1]);
+	assert_eq!(deque.front(), Some(&2));
+	assert_eq!(deque.back(), Some(&1));
+}
+
+#[test]
+fn init_deque_rotated_bounds() {
+	assert_eq!(init_deque_rotated_with(3, 0, |i| i), [0, 1, 2]);
+	assert_eq!(init_deque_rotated_with(3, 3, |i| i), [0, 1, 2]);
+	assert_eq!(init_deque_rotated_with(0, 0, |i| i), []);
+}
+
+#[test]
+#[should_panic]
+fn init_deque_rotated_out_of_range() {
+	init_deque_rotated_with(3, 4, |i| i);
+}
+
+#[cfg(feature = "std")]
+#[derive(Default)]
+struct Fnv(u64);
+
+#[cfg(feature = "std")]
+impl Hasher for Fnv {
+	fn finish(&self) -> u64 { self.0 }
+	
+	fn write(&mut self, bytes: &[u8]) {
+		for &b in bytes {
+			self.0 = (self.0 ^ b as u64).wrapping_mul(0x100_0000_01b3);
+		}
+	}
+}
+
+#[test]
+fn init_bounded_deque_keeps_recent() {
+	let mut calls = 0;
+	let window = init_bounded_deque_with(10, 3, |i| { calls += 1; i * i });
+	assert_eq!(window, [49, 64, 81]);
+	assert_eq!(calls, 10);
+}
+
+#[test]
+fn init_bounded_deque_zero_max() {
+	let window = init_bounded_deque_with(4, 0, |i| i);
+	assert!(window.is_empty());
+	
+	let window = init_bounded_deque_with(2, 5, |i| i);
+	assert_eq!(window, [0, 1]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_hash_map() {
+	let map = HashMap::<usize, String>::init_with(4, |i| (i, i.to_string()));
+	assert_eq!(map.len(), 4);
+	assert_eq!(map[&3], "3");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_hash_map_custom_hasher() {
+	let map = HashMap::<char, usize, BuildHasherDefault<Fnv>>::init_with(3, |i| ((b'a' + i as u8) as char, i * 10));
+	assert_eq!(map.len(), 3);
+	assert_eq!(map[&'a'], 0);
+	assert_eq!(map[&'b'], 10);
+	assert_eq!(map[&'c'], 20);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_hash_map_repeated_keys() {
+	let map = HashMap::<usize, usize>::init_with(6, |i| (i % 2, i));
+	assert_eq!(map.len(), 2);
+	assert_eq!(map[&0], 4);
+	assert_eq!(map[&1], 5);
+}
+
+#[test]
+fn init_sorted_map_increasing() {
+	let map = init_sorted_map(5, |i| (i * 10, (b'a' + i as u8) as char));
+	assert_eq!(map.len(), 5);
+	assert_eq!(map.iter().map(|(&k, &v)| (k, v)).collect::<Vec<_>>(), vec![(0, 'a'), (10, 'b'), (20, 'c'), (30, 'd'), (40, 'e')]);
+}
+
+#[test]
+fn init_sorted_map_empty() {
+	let map = init_sorted_map::<usize, usize, _>(0, |_| panic!("Shouldn't call init function"));
+	assert!(map.is_empty());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "key at index 3")]
+fn init_sorted_map_out_of_order() {
+	init_sorted_map(5, |i| ([0, 1, 2, 1, 4][i], i));
+}
+
+#[test]
+fn init_min_heap_pops_ascending() {
+	let mut heap = init_min_heap(10, |i| (i * 7) % 10);
+	let mut popped = Vec::new();
+	while let Some(Reverse(x)) = heap.pop() {
+		popped.push(x);
+	}
+	assert_eq!(popped, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn init_min_heap_peek_is_minimum() {
+	let heap = init_min_heap(5, |i| 100 - i as i32);
+	assert_eq!(heap.len(), 5);
+	assert_eq!(heap.peek(), Some(&Reverse(96)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_group_by_modulo() {
+	let groups = init_trait::init_group_by(10, |i| i % 3);
+	assert_eq!(groups.len(), 3);
+	assert_eq!(groups[&0], vec![0, 3, 6, 9]);
+	assert_eq!(groups[&1], vec![1, 4, 7]);
+	assert_eq!(groups[&2], vec![2, 5, 8]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_group_by_empty() {
+	let groups = init_trait::init_group_by(0, |_| -> u8 { panic!("Shouldn't call key function") });
+	assert!(groups.is_empty());
+}
@@ -0,0 +1,19 @@
+// Shared by several test crates, each of which only uses some of these
+#![allow(dead_code)]
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Counts its drops in the counter it borrows.
+pub struct Counted<'a>(pub &'a AtomicUsize);
+
+impl Drop for Counted<'_> {
+	fn drop(&mut self) { self.0.fetch_add(1, Ordering::SeqCst); }
+}
+
+/// Logs its index to the log it borrows when dropped, to check the drop order.
+pub struct LogDrop<'a>(pub usize, pub &'a RefCell<Vec<usize>>);
+
+impl Drop for LogDrop<'_> {
+	fn drop(&mut self) { self.1.borrow_mut().push(self.0); }
+}
@@ -0,0 +1,14 @@
+// Kept apart from `tests/array.rs`, since linking `trybuild` brings in comparisons between `usize`
+// and JSON values which stop `[]` being inferred there
+
+#[test]
+fn init_from_const_slice_ui() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/ui/const_slice_mismatch.rs");
+}
+
+#[test]
+fn init_const_permuted_ui() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/ui/const_permuted_mismatch.rs");
+}
@@ -0,0 +1,7 @@
+#![cfg(feature = "coroutine")]
+#![feature(coroutines)]
+
+// The `yield` syntax is rejected even inside disabled code, so the tests live in a module that is
+// only parsed when the feature is on
+#[path = "coroutine/yields.rs"]
+mod yields;
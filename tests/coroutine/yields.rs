@@ -0,0 +1,31 @@
+use init_trait::init_from_coroutine;
+
+#[test]
+fn init_from_coroutine_yields() {
+	let value = init_from_coroutine(#[coroutine] || {
+		yield "a";
+		yield "b";
+		yield "c";
+	});
+	assert_eq!(value, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn init_from_coroutine_borrows_state() {
+	let words = vec![String::from("x"), String::from("yz")];
+	let value = init_from_coroutine(#[coroutine] || {
+		for w in &words {
+			let len = w.len();
+			yield len;
+			yield len * 10;
+		}
+	});
+	assert_eq!(value, vec![1, 10, 2, 20]);
+	
+	let empty: Vec<u8> = init_from_coroutine(#[coroutine] || {
+		if false {
+			yield 0;
+		}
+	});
+	assert!(empty.is_empty());
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "strum")]
+
+use strum::{EnumCount, EnumIter};
+
+use init_trait::init_enum_indexed;
+
+#[derive(Clone, Copy, Debug, PartialEq, EnumCount, EnumIter)]
+enum Axis {
+	X,
+	Y,
+	Z,
+}
+
+#[test]
+fn init_enum_indexed_per_variant() {
+	let names: [&str; Axis::COUNT] = init_enum_indexed(|axis| match axis {
+		Axis::X => "x",
+		Axis::Y => "y",
+		Axis::Z => "z",
+	});
+	assert_eq!(names[Axis::X as usize], "x");
+	assert_eq!(names[Axis::Y as usize], "y");
+	assert_eq!(names[Axis::Z as usize], "z");
+}
+
+#[test]
+fn init_enum_indexed_declaration_order() {
+	let order: [Axis; 3] = init_enum_indexed(|axis| axis);
+	assert_eq!(order, [Axis::X, Axis::Y, Axis::Z]);
+}
+
+#[test]
+fn init_enum_indexed_ui() {
+	let t = trybuild::TestCases::new();
+	t.pass("tests/ui/enum_indexed_pass.rs");
+	t.compile_fail("tests/ui/enum_indexed_mismatch.rs");
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "fixed")]
+
+use fixed::types::{I16F16, U8F8};
+
+use init_trait::{FixedInit, Init};
+
+#[test]
+fn init_saturating_ramp() {
+	let ramp = <[I16F16; 8]>::init_saturating(|i| i as f64 * 0.25 - 1.0);
+	assert_eq!(ramp[0], I16F16::from_num(-1));
+	assert_eq!(ramp[4], I16F16::ZERO);
+	assert_eq!(ramp[7], I16F16::from_num(0.75));
+}
+
+#[test]
+fn init_saturating_clamps() {
+	let arr = <[U8F8; 3]>::init_saturating(|i| [-5.0, 1e9, 2.5][i]);
+	assert_eq!(arr, [U8F8::ZERO, U8F8::MAX, U8F8::from_num(2.5)]);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn init_saturating_from_fixed() {
+	let wide = Vec::<I16F16>::init_with(3, |i| I16F16::from_num(i as i32 * 200));
+	let narrow = Vec::<U8F8>::init_saturating_with(3, |i| wide[i]);
+	assert_eq!(narrow, [U8F8::ZERO, U8F8::from_num(200), U8F8::MAX]);
+}
+
+#[test]
+fn init_saturating_primitive() {
+	let arr = <[u8; 3]>::init_saturating(|i| [-1i32, 100, 1000][i]);
+	assert_eq!(arr, [0, 100, 255]);
+}
@@ -0,0 +1,222 @@
+mod common;
+
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use init_trait::{Init, GridInit, Grid3Init, Grid4Init, Row, Column};
+
+use common::Counted;
+
+#[test]
+fn init_hilbert_values() {
+	let grid = <[[(usize, usize); 8]; 8]>::init_hilbert_with(|[r, c]| (r, c));
+	for (r, row) in grid.iter().enumerate() {
+		for (c, &cell) in row.iter().enumerate() {
+			assert_eq!(cell, (r, c));
+		}
+	}
+}
+
+#[test]
+fn init_hilbert_order() {
+	let mut order = Vec::new();
+	<[[(); 16]; 16]>::init_hilbert_with(|[r, c]| order.push((r, c)));
+	
+	assert_eq!(order.len(), 16 * 16);
+	assert_eq!(order[0], (0, 0));
+	
+	let mut seen = [[false; 16]; 16];
+	for &(r, c) in &order {
+		assert!(!seen[r][c], "Visited ({}, {}) twice", r, c);
+		seen[r][c] = true;
+	}
+	
+	for pair in order.windows(2) {
+		let (r1, c1) = pair[0];
+		let (r2, c2) = pair[1];
+		assert_eq!(r1.max(r2) - r1.min(r2) + c1.max(c2) - c1.min(c2), 1, "{:?} isn't adjacent to {:?}", pair[0], pair[1]);
+	}
+}
+
+#[test]
+fn init_hilbert_trivial() {
+	assert_eq!(<[[usize; 1]; 1]>::init_hilbert_with(|_| 7), [[7]]);
+	let empty = <[[usize; 0]; 0]>::init_hilbert_with(|_| panic!("Shouldn't call init function"));
+	assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn init_hilbert_not_power_of_two() {
+	<[[usize; 3]; 3]>::init_hilbert_with(|_| 0);
+}
+
+#[test]
+#[should_panic]
+fn init_hilbert_not_square() {
+	<[[usize; 4]; 2]>::init_hilbert_with(|_| 0);
+}
+
+#[test]
+fn init_hilbert_panic_drops_visited() {
+	let calls = Cell::new(0);
+	let drops = AtomicUsize::new(0);
+	let res = catch_unwind(AssertUnwindSafe(|| {
+		<[[Counted; 4]; 4]>::init_hilbert_with(|_| {
+			if calls.get() == 7 { panic!("Init function failed"); }
+			calls.set(calls.get() + 1);
+			Counted(&drops)
+		})
+	}));
+	assert!(res.is_err());
+	assert_eq!(drops.load(Ordering::SeqCst), 7);
+}
+
+#[derive(Debug, PartialEq)]
+struct Lat(usize);
+#[derive(Debug, PartialEq)]
+struct Lon(usize);
+
+impl From<Row> for Lat { fn from(Row(r): Row) -> Self { Lat(r) } }
+impl From<Column> for Lon { fn from(Column(c): Column) -> Self { Lon(c) } }
+
+#[test]
+fn init_typed_indices() {
+	let mut order = Vec::new();
+	let grid = <[[(usize, usize); 3]; 2]>::init_typed(|(Row(r), Column(c))| { order.push((r, c)); (r, c) });
+	assert_eq!(grid, [[(0, 0), (0, 1), (0, 2)], [(1, 0), (1, 1), (1, 2)]]);
+	assert_eq!(order.len(), 6);
+}
+
+#[test]
+fn init_typed_custom_indices() {
+	let grid = <[[(Lat, Lon); 4]; 3]>::init_typed(|(lat, lon): (Lat, Lon)| (lat, lon));
+	assert_eq!(grid[2][3], (Lat(2), Lon(3)));
+}
+
+#[test]
+fn init_typed_ui() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/ui/typed_index_swapped.rs");
+}
+
+#[test]
+fn init_2d_separate_args() {
+	let grid = <[[(usize, usize); 34]; 12]>::init_2d(|x, y| (x, y));
+	for (x, row) in grid.iter().enumerate() {
+		for (y, &cell) in row.iter().enumerate() {
+			assert_eq!(cell, (x, y));
+		}
+	}
+}
+
+#[test]
+fn init_3d_separate_args() {
+	let grid = <[[[(usize, usize, usize); 34]; 23]; 12]>::init_3d(|x, y, z| (x, y, z));
+	for (x, plane) in grid.iter().enumerate() {
+		for (y, row) in plane.iter().enumerate() {
+			for (z, &cell) in row.iter().enumerate() {
+				assert_eq!(cell, (x, y, z));
+			}
+		}
+	}
+}
+
+#[test]
+fn init_4d_separate_args() {
+	let grid = <[[[[(usize, usize, usize, usize); 5]; 4]; 3]; 2]>::init_4d(|w, x, y, z| (w, x, y, z));
+	for (w, cube) in grid.iter().enumerate() {
+		for (x, plane) in cube.iter().enumerate() {
+			for (y, row) in plane.iter().enumerate() {
+				for (z, &cell) in row.iter().enumerate() {
+					assert_eq!(cell, (w, x, y, z));
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn init_3d_order_calls() {
+	let mut order = Vec::new();
+	let grid = <[[[usize; 3]; 2]; 2]>::init_3d_order([0, 1, 2], |[l, r, c]| { order.push([l, r, c]); l * 100 + r * 10 + c });
+	assert_eq!(grid, <[[[usize; 3]; 2]; 2]>::init_3d(|l, r, c| l * 100 + r * 10 + c));
+	assert_eq!(order[..4], [[0, 0, 0], [0, 0, 1], [0, 0, 2], [0, 1, 0]]);
+	
+	let mut order = Vec::new();
+	let grid = <[[[usize; 3]; 2]; 2]>::init_3d_order([2, 1, 0], |[l, r, c]| { order.push([l, r, c]); l * 100 + r * 10 + c });
+	assert_eq!(grid, <[[[usize; 3]; 2]; 2]>::init_3d(|l, r, c| l * 100 + r * 10 + c));
+	assert_eq!(order[..4], [[0, 0, 0], [1, 0, 0], [0, 1, 0], [1, 1, 0]]);
+	assert_eq!(order.len(), 12);
+}
+
+#[test]
+fn init_3d_order_panic_drops_all() {
+	let drops = AtomicUsize::new(0);
+	
+	let mut calls = 0;
+	let res = catch_unwind(AssertUnwindSafe(|| <[[[Counted; 4]; 3]; 2]>::init_3d_order([1, 2, 0], |_| {
+		calls += 1;
+		if calls == 10 { panic!("Init function failed"); }
+		Counted(&drops)
+	})));
+	assert!(res.is_err());
+	assert_eq!(drops.load(Ordering::SeqCst), 9);
+}
+
+#[test]
+#[should_panic(expected = "permutation")]
+fn init_3d_order_invalid() {
+	<[[[u8; 2]; 2]; 2]>::init_3d_order([0, 0, 1], |_| 0);
+}
+
+#[test]
+fn init_rows_repeated() {
+	let grid = <[[usize; 3]; 4]>::init_rows(|r| [r; 3]);
+	assert_eq!(grid, [[0, 0, 0], [1, 1, 1], [2, 2, 2], [3, 3, 3]]);
+}
+
+#[test]
+fn init_rows_called_once_per_row() {
+	let mut calls = Vec::new();
+	let grid = <[[char; 2]; 3]>::init_rows(|r| { calls.push(r); ['a', 'b'] });
+	assert_eq!(calls, vec![0, 1, 2]);
+	assert_eq!(grid[2], ['a', 'b']);
+}
+
+#[test]
+fn init_morton_order_4x4() {
+	let mut order = Vec::new();
+	let grid = <[[(usize, usize); 4]; 4]>::init_morton_with(|[r, c]| { order.push([r, c]); (r, c) });
+	assert_eq!(grid, <[[(usize, usize); 4]; 4]>::init_2d(|r, c| (r, c)));
+	assert_eq!(order, [
+		[0, 0], [0, 1], [1, 0], [1, 1],
+		[0, 2], [0, 3], [1, 2], [1, 3],
+		[2, 0], [2, 1], [3, 0], [3, 1],
+		[2, 2], [2, 3], [3, 2], [3, 3],
+	]);
+}
+
+#[test]
+#[should_panic(expected = "power of two")]
+fn init_morton_non_power_of_two() {
+	<[[u8; 3]; 3]>::init_morton_with(|_| 0);
+}
+
+#[test]
+fn init_by_columns_constant() {
+	let grid = <[[usize; 4]; 3]>::init_by_columns(|c| [c; 3]);
+	for row in &grid {
+		for (c, &cell) in row.iter().enumerate() {
+			assert_eq!(cell, c);
+		}
+	}
+}
+
+#[test]
+fn init_by_columns_matches_rows() {
+	let by_rows = <[[String; 3]; 2]>::init_rows(|r| <[String; 3]>::init(|c| format!("{}{}", r, c)));
+	let by_columns = <[[String; 3]; 2]>::init_by_columns(|c| <[String; 2]>::init(|r| format!("{}{}", r, c)));
+	assert_eq!(by_rows, by_columns);
+}
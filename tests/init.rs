@@ -1,5 +1,11 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use init_trait::Init;
 
+use common::{Counted, LogDrop};
+
 #[test]
 fn init_empty_array() {
 	let arr = <[usize; 0]>::init(|_| panic!("Shouldn't call init function"));
@@ -127,3 +133,124 @@ fn init_vec() {
 		assert_eq!(arr[i], i);
 	}
 }
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn init_vec_of_owned() {
+	let arr = Vec::init_with(50, |i| vec![i; i]);
+	assert_eq!(arr.len(), 50);
+	for (i, elem) in arr.iter().enumerate() {
+		assert_eq!(*elem, vec![i; i]);
+	}
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn init_vec_panic_drops_prefix() {
+	use std::panic::{catch_unwind, AssertUnwindSafe};
+	
+	let drops = AtomicUsize::new(0);
+	let res = catch_unwind(AssertUnwindSafe(|| {
+		Vec::init_with(10, |i| {
+			if i == 4 { panic!("Init function failed"); }
+			Counted(&drops)
+		})
+	}));
+	assert!(res.is_err());
+	assert_eq!(drops.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn init_array_panic_drops_prefix() {
+	use std::panic::{catch_unwind, AssertUnwindSafe};
+	
+	let drops = AtomicUsize::new(0);
+	let res = catch_unwind(AssertUnwindSafe(|| {
+		<[Counted; 10]>::init(|i| {
+			if i == 4 { panic!("Init function failed"); }
+			Counted(&drops)
+		})
+	}));
+	assert!(res.is_err());
+	assert_eq!(drops.load(Ordering::SeqCst), 4);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn init_vec_of_trait_objects() {
+	trait Handler {
+		fn handle(&self, x: usize) -> usize;
+	}
+	
+	struct Add(usize);
+	struct Mul(usize);
+	
+	impl Handler for Add {
+		fn handle(&self, x: usize) -> usize { x + self.0 }
+	}
+	
+	impl Handler for Mul {
+		fn handle(&self, x: usize) -> usize { x * self.0 }
+	}
+	
+	let handlers = Vec::<Box<dyn Handler>>::init_with(4, |i| {
+		if i % 2 == 0 { Box::new(Add(i)) } else { Box::new(Mul(i)) }
+	});
+	assert_eq!(handlers.len(), 4);
+	for (i, handler) in handlers.iter().enumerate() {
+		let expected = if i % 2 == 0 { 10 + i } else { 10 * i };
+		assert_eq!(handler.handle(10), expected);
+	}
+}
+
+#[test]
+fn init_array_panic_drops_in_reverse() {
+	use std::cell::RefCell;
+	use std::panic::{catch_unwind, AssertUnwindSafe};
+	
+	let log = RefCell::new(Vec::new());
+	let res = catch_unwind(AssertUnwindSafe(|| {
+		<[LogDrop; 8]>::init(|i| {
+			if i == 5 { panic!("Init function failed"); }
+			LogDrop(i, &log)
+		})
+	}));
+	assert!(res.is_err());
+	assert_eq!(*log.borrow(), vec![4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn init_2d_array_panic_drops_in_reverse() {
+	use std::cell::RefCell;
+	use std::panic::{catch_unwind, AssertUnwindSafe};
+	
+	let log = RefCell::new(Vec::new());
+	let res = catch_unwind(AssertUnwindSafe(|| {
+		<[[LogDrop; 3]; 3] as Init<LogDrop, [usize; 2]>>::init(|[x, y]| {
+			if [x, y] == [1, 1] { panic!("Init function failed"); }
+			LogDrop(x * 3 + y, &log)
+		})
+	}));
+	assert!(res.is_err());
+	assert_eq!(*log.borrow(), vec![3, 2, 1, 0]);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn init_vec_panic_drops_in_reverse() {
+	use std::cell::RefCell;
+	use std::panic::{catch_unwind, AssertUnwindSafe};
+	
+	// Short and long `Vec`s are filled differently, so check both
+	for &length in &[4, 8, 20] {
+		let log = RefCell::new(Vec::new());
+		let res = catch_unwind(AssertUnwindSafe(|| {
+			Vec::init_with(length, |i| {
+				if i == 3 { panic!("Init function failed"); }
+				LogDrop(i, &log)
+			})
+		}));
+		assert!(res.is_err());
+		assert_eq!(*log.borrow(), vec![2, 1, 0], "length {}", length);
+	}
+}
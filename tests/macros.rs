@@ -0,0 +1,44 @@
+use init_trait::init;
+
+#[test]
+fn init_macro_1d() {
+	let arr = init!([usize; 4] => |i| i * 2);
+	assert_eq!(arr, [0, 2, 4, 6]);
+}
+
+#[test]
+fn init_macro_2d() {
+	let arr = init!([usize; 12] [usize; 34] => |x, y| (x, y));
+	for (x, row) in arr.iter().enumerate() {
+		for (y, &cell) in row.iter().enumerate() {
+			assert_eq!(cell, (x, y));
+		}
+	}
+}
+
+#[test]
+fn init_macro_3d() {
+	let arr = init!([usize; 2][usize; 3][usize; 4] => |x, y, z| (x, y, z));
+	for (x, plane) in arr.iter().enumerate() {
+		for (y, row) in plane.iter().enumerate() {
+			for (z, &cell) in row.iter().enumerate() {
+				assert_eq!(cell, (x, y, z));
+			}
+		}
+	}
+}
+
+#[test]
+fn init_macro_call_order() {
+	let mut calls = Vec::new();
+	let arr = init!([usize; 2][usize; 2] => |x, y| { calls.push((x, y)); x + y });
+	assert_eq!(arr, [[0, 1], [1, 2]]);
+	assert_eq!(calls, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+}
+
+#[test]
+fn init_macro_ui() {
+	let t = trybuild::TestCases::new();
+	t.pass("tests/ui/init_macro_pass.rs");
+	t.compile_fail("tests/ui/init_macro_mismatch.rs");
+}
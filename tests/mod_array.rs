@@ -0,0 +1,29 @@
+use init_trait::{Init, ModArray};
+
+#[test]
+fn mod_array_wraps() {
+	const N: usize = 5;
+	let arr = <[usize; N]>::init(|i| i * i);
+	let wrapped = ModArray::<usize, N>::init(|i| i * i);
+	assert_eq!(*wrapped.get(N + 3), arr[3]);
+	assert_eq!(wrapped[2 * N], arr[0]);
+	assert_eq!(wrapped.into_inner(), arr);
+}
+
+#[test]
+fn mod_array_signed() {
+	let wrapped = ModArray::new(['a', 'b', 'c']);
+	assert_eq!(*wrapped.get_signed(-1), 'c');
+	assert_eq!(*wrapped.get_signed(-3), 'a');
+	assert_eq!(*wrapped.get_signed(-7), 'c');
+	assert_eq!(*wrapped.get_signed(4), 'b');
+	assert_eq!(*wrapped.get_signed(isize::MIN), wrapped[(isize::MIN as i128).rem_euclid(3) as usize]);
+}
+
+#[test]
+fn mod_array_mutation() {
+	let mut wrapped = ModArray::from([0u8; 4]);
+	wrapped[5] = 1;
+	*wrapped.get_signed_mut(-2) = 2;
+	assert_eq!(wrapped.as_array(), &[0, 1, 2, 0]);
+}
@@ -0,0 +1,26 @@
+use std::num::{NonZeroU8, NonZeroU32, NonZeroI64};
+
+use init_trait::NonZeroInit;
+
+#[test]
+fn init_nonzero() {
+	let arr = <[NonZeroU32; 4]>::init_nonzero(|i| (i as u32 + 1) * 10).unwrap();
+	assert_eq!(arr.iter().map(|x| x.get()).collect::<Vec<_>>(), vec![10, 20, 30, 40]);
+}
+
+#[test]
+fn init_nonzero_error_index() {
+	let mut calls = 0;
+	let res = <[NonZeroU32; 5]>::init_nonzero(|i| {
+		calls += 1;
+		if i == 2 { 0 } else { 1 }
+	});
+	assert_eq!(res, Err(2));
+	assert_eq!(calls, 3);
+}
+
+#[test]
+fn init_nonzero_other_widths() {
+	assert_eq!(<[NonZeroU8; 3]>::init_nonzero(|i| 255 - i as u8).map(|arr| arr[2].get()), Ok(253));
+	assert_eq!(<[NonZeroI64; 3]>::init_nonzero(|i| i as i64 - 1), Err(1));
+}
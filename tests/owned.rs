@@ -0,0 +1,21 @@
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use init_trait::OwnedInit;
+
+#[test]
+fn init_owned_slice() {
+	let vec: Vec<usize> = <[usize]>::init_owned_with(4, |i| i * i);
+	assert_eq!(vec, vec![0, 1, 4, 9]);
+}
+
+#[test]
+fn init_owned_str() {
+	let string: String = str::init_owned_with(5, |i| if i % 2 == 0 { 'a' } else { 'b' });
+	assert_eq!(string, "ababa");
+}
+
+#[test]
+fn init_owned_empty() {
+	assert!(<[u8]>::init_owned_with(0, |_| panic!("Shouldn't call init function")).is_empty());
+	assert!(str::init_owned_with(0, |_| panic!("Shouldn't call init function")).is_empty());
+}
@@ -0,0 +1,17 @@
+#![cfg(all(feature = "rayon", feature = "std"))]
+
+use rayon::prelude::*;
+
+use init_trait::{Init, par_init_from_indices};
+
+#[test]
+fn par_init_matches_sequential() {
+	let par = par_init_from_indices((0..1000).into_par_iter(), |i| i * 7 % 13);
+	assert_eq!(par, Vec::init_with(1000, |i| i * 7 % 13));
+}
+
+#[test]
+fn par_init_custom_partitioning() {
+	let par = par_init_from_indices((0..100).into_par_iter().rev().with_min_len(16), |i| i);
+	assert_eq!(par, (0..100).rev().collect::<Vec<_>>());
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "rand")]
+
+use rand::{SeedableRng, rngs::StdRng};
+
+use init_trait::RandomInit;
+
+#[test]
+fn init_random_array_reproducible() {
+	let a = <[u32; 8]>::init_random(&mut StdRng::seed_from_u64(42));
+	let b = <[u32; 8]>::init_random(&mut StdRng::seed_from_u64(42));
+	let c = <[u32; 8]>::init_random(&mut StdRng::seed_from_u64(43));
+	assert_eq!(a, b);
+	assert_ne!(a, c);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn init_random_vec_reproducible() {
+	let a = Vec::<u64>::init_random_with(100, &mut StdRng::seed_from_u64(7));
+	let b = Vec::<u64>::init_random_with(100, &mut StdRng::seed_from_u64(7));
+	assert_eq!(a.len(), 100);
+	assert_eq!(a, b);
+}
+
+#[test]
+fn init_random_dyn_rng() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let rng: &mut dyn rand::RngCore = &mut rng;
+	let arr = <[bool; 64]>::init_random(rng);
+	assert!(arr.iter().any(|&b| b) && arr.iter().any(|&b| !b));
+}
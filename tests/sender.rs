@@ -0,0 +1,28 @@
+#![cfg(feature = "std")]
+
+use std::sync::mpsc::channel;
+use std::thread;
+
+use init_trait::init_to_sender;
+
+#[test]
+fn init_to_sender_consumer_order() {
+	let (tx, rx) = channel();
+	let consumer = thread::spawn(move || rx.iter().collect::<Vec<_>>());
+	
+	init_to_sender(100, &tx, |i| i * i).unwrap();
+	drop(tx);
+	
+	assert_eq!(consumer.join().unwrap(), (0..100).map(|i| i * i).collect::<Vec<_>>());
+}
+
+#[test]
+fn init_to_sender_receiver_dropped() {
+	let (tx, rx) = channel();
+	drop(rx);
+	
+	let mut calls = 0;
+	let err = init_to_sender(5, &tx, |i| { calls += 1; i + 10 }).unwrap_err();
+	assert_eq!(err.0, 10);
+	assert_eq!(calls, 1);
+}
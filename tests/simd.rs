@@ -0,0 +1,19 @@
+#![cfg(feature = "simd")]
+#![feature(portable_simd)]
+
+use std::simd::Simd;
+
+use init_trait::{Init, init_simd};
+
+#[test]
+fn init_simd_f32() {
+	let v: Simd<f32, 4> = init_simd(|i| i as f32);
+	assert_eq!(v.to_array(), [0.0, 1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn init_simd_impl() {
+	let v = Simd::<u8, 16>::init(|i| (i * 3) as u8);
+	assert_eq!(v[5], 15);
+	assert_eq!(v + Simd::splat(1), Simd::init(|i| (i * 3 + 1) as u8));
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "smallvec")]
+
+use init_trait::{Init, SmallGrid};
+
+#[test]
+fn init_small_grid_inline() {
+	let grid = SmallGrid::<(usize, usize), 16>::init_with([3, 4], |[r, c]| (r, c));
+	assert!(!grid.spilled());
+	assert_eq!(grid.rows(), 3);
+	assert_eq!(grid.columns(), 4);
+	for r in 0..3 {
+		for c in 0..4 {
+			assert_eq!(grid[[r, c]], (r, c));
+		}
+	}
+}
+
+#[test]
+fn init_small_grid_spilled() {
+	let grid = SmallGrid::<usize, 16>::init_with([5, 5], |[r, c]| r * 5 + c);
+	assert!(grid.spilled());
+	assert_eq!(grid.as_slice(), &(0..25).collect::<Vec<_>>()[..]);
+}
+
+#[test]
+fn small_grid_bounds() {
+	let mut grid = SmallGrid::<usize, 4>::init_with([2, 2], |[r, c]| r + c);
+	assert_eq!(grid.get([1, 1]), Some(&2));
+	assert_eq!(grid.get([0, 2]), None);
+	assert_eq!(grid.get([2, 0]), None);
+	grid[[0, 1]] = 10;
+	assert_eq!(grid.as_slice(), &[0, 10, 1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn small_grid_index_out_of_bounds() {
+	let grid = SmallGrid::<usize, 4>::init_with([2, 2], |_| 0);
+	let _ = grid[[0, 2]];
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "stable")]
+
+use generic_array::{GenericArray, typenum::{U0, U4}};
+
+use init_trait::Init;
+
+#[test]
+fn init_generic_array() {
+	let arr = GenericArray::<usize, U4>::init(|i| i * i);
+	assert_eq!(arr.as_slice(), &[0, 1, 4, 9]);
+}
+
+#[test]
+fn init_empty_generic_array() {
+	let arr = GenericArray::<usize, U0>::init(|_| panic!("Shouldn't call init function"));
+	assert!(arr.is_empty());
+}
@@ -0,0 +1,41 @@
+#![cfg(all(feature = "step_trait", any(feature = "std", feature = "alloc")))]
+#![feature(step_trait)]
+
+use std::iter::Step;
+
+use init_trait::VecInit;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Day(u32);
+
+impl Step for Day {
+	fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+		u32::steps_between(&start.0, &end.0)
+	}
+	
+	fn forward_checked(start: Self, count: usize) -> Option<Self> {
+		u32::forward_checked(start.0, count).map(Day)
+	}
+	
+	fn backward_checked(start: Self, count: usize) -> Option<Self> {
+		u32::backward_checked(start.0, count).map(Day)
+	}
+}
+
+#[test]
+fn init_steps_newtype() {
+	let vec = Vec::init_steps(Day(3), Day(7), |Day(d)| d * 10);
+	assert_eq!(vec, vec![30, 40, 50, 60]);
+}
+
+#[test]
+fn init_steps_empty() {
+	let vec = Vec::<u32>::init_steps(Day(5), Day(5), |_| panic!("Shouldn't call init function"));
+	assert!(vec.is_empty());
+}
+
+#[test]
+fn init_steps_usize() {
+	let vec = Vec::init_steps(2usize, 5, |i| i * i);
+	assert_eq!(vec, vec![4, 9, 16]);
+}
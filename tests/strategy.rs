@@ -0,0 +1,33 @@
+use std::cell::RefCell;
+
+use init_trait::{ArrayInit, Sequential, Reversed};
+
+#[test]
+fn init_strategy_sequential() {
+	let order = RefCell::new(Vec::new());
+	let arr = <[usize; 4]>::init_strategy::<Sequential, _>(|i| { order.borrow_mut().push(i); i * 2 });
+	assert_eq!(arr, [0, 2, 4, 6]);
+	assert_eq!(*order.borrow(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn init_strategy_reversed() {
+	let order = RefCell::new(Vec::new());
+	let arr = <[usize; 4]>::init_strategy::<Reversed, _>(|i| { order.borrow_mut().push(i); i * 2 });
+	assert_eq!(arr, [0, 2, 4, 6]);
+	assert_eq!(*order.borrow(), vec![3, 2, 1, 0]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_strategy_parallel() {
+	use std::sync::Mutex;
+	use init_trait::Parallel;
+	
+	let order = Mutex::new(Vec::new());
+	let arr = <[usize; 64]>::init_strategy::<Parallel, _>(|i| { order.lock().unwrap().push(i); i * 2 });
+	assert_eq!(arr, <[usize; 64]>::init_strategy::<Sequential, _>(|i| i * 2));
+	let mut order = order.into_inner().unwrap();
+	order.sort_unstable();
+	assert_eq!(order, (0..64).collect::<Vec<_>>());
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "stream")]
+
+use futures::{executor::block_on, stream};
+
+use init_trait::init_from_stream;
+
+#[test]
+fn init_from_stream_iter() {
+	let vec = block_on(init_from_stream(stream::iter(0..5)));
+	assert_eq!(vec, vec![0, 1, 2, 3, 4]);
+	assert!(vec.capacity() >= 5);
+}
+
+#[test]
+fn init_from_stream_async_items() {
+	use futures::StreamExt;
+	
+	let delayed = stream::iter(0..3).then(|i| async move {
+		futures::future::ready(()).await;
+		i * 2
+	});
+	assert_eq!(block_on(init_from_stream(delayed)), vec![0, 2, 4]);
+}
+
+#[test]
+fn init_from_stream_empty() {
+	assert!(block_on(init_from_stream(stream::empty::<u8>())).is_empty());
+}
+
+/// A future which is pending `polls` times before completing.
+struct Slow {
+	polls: usize,
+	value: usize,
+}
+
+impl std::future::Future for Slow {
+	type Output = usize;
+	
+	fn poll(mut self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<usize> {
+		if self.polls == 0 {
+			std::task::Poll::Ready(self.value)
+		} else {
+			self.polls -= 1;
+			cx.waker().wake_by_ref();
+			std::task::Poll::Pending
+		}
+	}
+}
+
+#[test]
+fn init_with_async_buffered_order() {
+	use std::cell::Cell;
+	
+	let in_flight = Cell::new(0);
+	let max_in_flight = Cell::new(0);
+	let vec = block_on(init_trait::init_with_async_buffered(6, 2, |i| {
+		let (in_flight, max_in_flight) = (&in_flight, &max_in_flight);
+		async move {
+			in_flight.set(in_flight.get() + 1);
+			max_in_flight.set(max_in_flight.get().max(in_flight.get()));
+			let x = Slow { polls: 6 - i, value: i * i }.await;
+			in_flight.set(in_flight.get() - 1);
+			x
+		}
+	}));
+	assert_eq!(vec, vec![0, 1, 4, 9, 16, 25]);
+	assert_eq!(max_in_flight.get(), 2);
+}
+
+#[test]
+fn init_with_async_buffered_zero_concurrency() {
+	let vec = block_on(init_trait::init_with_async_buffered(3, 0, |i| Slow { polls: 1, value: i }));
+	assert_eq!(vec, vec![0, 1, 2]);
+}
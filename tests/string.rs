@@ -0,0 +1,25 @@
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use std::borrow::Cow;
+
+use init_trait::Init;
+
+#[test]
+fn init_string() {
+	let s = String::init_with(5, |i| (b'a' + i as u8) as char);
+	assert_eq!(s, "abcde");
+}
+
+#[test]
+fn init_string_multibyte() {
+	let s = String::init_with(3, |i| ['é', '∑', '🦀'][i]);
+	assert_eq!(s, "é∑🦀");
+	assert_eq!(s.chars().count(), 3);
+}
+
+#[test]
+fn init_cow_str() {
+	let s = Cow::<str>::init_with(4, |i| if i % 2 == 0 { 'x' } else { 'o' });
+	assert!(matches!(s, Cow::Owned(_)));
+	assert_eq!(s, "xoxo");
+}
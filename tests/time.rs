@@ -0,0 +1,61 @@
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use std::time::Duration;
+
+use init_trait::{init_backoff, init_backoff_capped};
+
+#[test]
+fn init_backoff_doubling() {
+	let schedule = init_backoff(Duration::from_secs(1), 2.0, 5);
+	assert_eq!(schedule, vec![1, 2, 4, 8, 16].into_iter().map(Duration::from_secs).collect::<Vec<_>>());
+}
+
+#[test]
+fn init_backoff_saturates() {
+	let schedule = init_backoff(Duration::from_secs(1), 1e10, 4);
+	assert_eq!(schedule[0], Duration::from_secs(1));
+	assert_eq!(schedule[1], Duration::from_secs(10_000_000_000));
+	assert_eq!(schedule[2], Duration::MAX);
+	assert_eq!(schedule[3], Duration::MAX);
+}
+
+#[test]
+fn init_backoff_cap() {
+	let cap = Duration::from_millis(500);
+	let schedule = init_backoff_capped(Duration::from_millis(100), 2.0, cap, 5);
+	assert_eq!(schedule, vec![100, 200, 400, 500, 500].into_iter().map(Duration::from_millis).collect::<Vec<_>>());
+}
+
+#[test]
+fn init_backoff_empty() {
+	assert!(init_backoff(Duration::from_secs(1), 2.0, 0).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn init_backoff_negative_factor() {
+	init_backoff(Duration::from_secs(1), -2.0, 3);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_profiled_lengths() {
+	use init_trait::init_profiled_with;
+	
+	let (values, durations) = init_profiled_with(10, |i| i + 1);
+	assert_eq!(values, (1..=10).collect::<Vec<_>>());
+	assert_eq!(durations.len(), 10);
+	
+	let (values, durations) = init_profiled_with(0, |i| i);
+	assert!(values.is_empty() && durations.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_profiled_finds_slow_index() {
+	use init_trait::init_profiled_with;
+	
+	let (_, durations) = init_profiled_with(4, |i| if i == 2 { std::thread::sleep(Duration::from_millis(30)) });
+	assert!(durations[2] >= Duration::from_millis(30));
+	assert_eq!(durations.iter().enumerate().max_by_key(|&(_, d)| d).unwrap().0, 2);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "std")]
+
+use std::panic::catch_unwind;
+use std::thread::sleep;
+use std::time::Duration;
+
+use init_trait::{TimeoutError, init_with_timeout};
+
+#[test]
+fn init_with_timeout_slow_index() {
+	let res = init_with_timeout(5, Duration::from_millis(100), |i| {
+		if i == 2 { sleep(Duration::from_millis(500)); }
+		i
+	});
+	assert_eq!(res, Err(TimeoutError { index: 2 }));
+	assert_eq!(TimeoutError { index: 2 }.to_string(), "initialising element 2 timed out");
+}
+
+#[test]
+fn init_with_timeout_fast() {
+	let res = init_with_timeout(100, Duration::from_secs(5), |i| i.to_string());
+	assert_eq!(res.unwrap()[42], "42");
+	assert_eq!(init_with_timeout(0, Duration::from_millis(1), |_| -> u8 { panic!("Shouldn't call init function") }), Ok(vec![]));
+}
+
+#[test]
+fn init_with_timeout_panic_propagates() {
+	let res = catch_unwind(|| init_with_timeout(4, Duration::from_secs(5), |i| {
+		if i == 1 { panic!("Init function failed"); }
+		i
+	}));
+	assert_eq!(res.unwrap_err().downcast_ref::<&str>(), Some(&"Init function failed"));
+}
@@ -0,0 +1,79 @@
+#![cfg(all(feature = "tracing", feature = "std"))]
+
+use std::sync::{Arc, Mutex};
+
+use tracing::{Event, Metadata, Subscriber};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::with_default;
+
+use init_trait::init_traced_with;
+
+#[derive(Default)]
+struct Log {
+	spans: Vec<(String, u64)>,
+	events: Vec<u64>,
+}
+
+/// Records each new span with its `index` field, and the span each event happens in.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Log>>, Arc<Mutex<Vec<u64>>>);
+
+struct IndexVisitor(Option<u64>);
+
+impl Visit for IndexVisitor {
+	fn record_u64(&mut self, field: &Field, value: u64) {
+		if field.name() == "index" { self.0 = Some(value); }
+	}
+	
+	fn record_debug(&mut self, _: &Field, _: &dyn std::fmt::Debug) { }
+}
+
+impl Subscriber for Recorder {
+	fn enabled(&self, _: &Metadata<'_>) -> bool { true }
+	
+	fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+		let mut visitor = IndexVisitor(None);
+		attrs.record(&mut visitor);
+		let mut log = self.0.lock().unwrap();
+		log.spans.push((attrs.metadata().name().to_string(), visitor.0.unwrap()));
+		Id::from_u64(log.spans.len() as u64)
+	}
+	
+	fn record(&self, _: &Id, _: &Record<'_>) { }
+	
+	fn record_follows_from(&self, _: &Id, _: &Id) { }
+	
+	fn event(&self, _: &Event<'_>) {
+		let current = *self.1.lock().unwrap().last().unwrap();
+		self.0.lock().unwrap().events.push(current);
+	}
+	
+	fn enter(&self, span: &Id) {
+		self.1.lock().unwrap().push(span.into_u64());
+	}
+	
+	fn exit(&self, _: &Id) {
+		self.1.lock().unwrap().pop();
+	}
+}
+
+#[test]
+fn init_traced_span_per_element() {
+	let recorder = Recorder::default();
+	let value = with_default(recorder.clone(), || init_traced_with(4, |i| i * 3));
+	assert_eq!(value, vec![0, 3, 6, 9]);
+	
+	let log = recorder.0.lock().unwrap();
+	let expected: Vec<_> = (0..4).map(|i| ("init_elem".to_string(), i)).collect();
+	assert_eq!(log.spans, expected);
+	assert_eq!(log.events, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn init_traced_empty() {
+	let recorder = Recorder::default();
+	let value: Vec<u8> = with_default(recorder.clone(), || init_traced_with(0, |_| unreachable!()));
+	assert!(value.is_empty());
+	assert!(recorder.0.lock().unwrap().spans.is_empty());
+}
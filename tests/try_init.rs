@@ -0,0 +1,95 @@
+mod common;
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use init_trait::TryInit;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use init_trait::InitError;
+
+use common::Counted;
+
+#[test]
+fn try_init_array() {
+	let arr = <[usize; 5]>::try_init(|i| Ok::<_, ()>(i * 2));
+	assert_eq!(arr, Ok([0, 2, 4, 6, 8]));
+}
+
+#[test]
+fn try_init_array_error() {
+	let mut calls = 0;
+	let arr = <[usize; 5]>::try_init(|i| {
+		calls += 1;
+		if i == 2 { Err(i) } else { Ok(i) }
+	});
+	assert_eq!(arr, Err(2));
+	assert_eq!(calls, 3);
+}
+
+#[test]
+fn try_init_array_error_drops_prefix() {
+	let drops = AtomicUsize::new(0);
+	let res = <[Counted; 10]>::try_init(|i| if i == 6 { Err(i) } else { Ok(Counted(&drops)) });
+	assert!(res.is_err());
+	assert_eq!(drops.load(Ordering::SeqCst), 6);
+}
+
+#[test]
+fn try_init_2d_array() {
+	let arr = <[[(usize, usize); 3]; 2] as TryInit<(usize, usize), [usize; 2]>>::try_init(|[x, y]| Ok::<_, ()>((x, y)));
+	assert_eq!(arr, Ok([[(0, 0), (0, 1), (0, 2)], [(1, 0), (1, 1), (1, 2)]]));
+}
+
+#[test]
+fn try_init_3d_array_error_drops_across_dimensions() {
+	let built = Cell::new(0);
+	let drops = AtomicUsize::new(0);
+	let res = <[[[Counted; 4]; 3]; 2] as TryInit<Counted, [usize; 3]>>::try_init(|[x, y, z]| {
+		if [x, y, z] == [1, 1, 2] { return Err([x, y, z]); }
+		built.set(built.get() + 1);
+		Ok(Counted(&drops))
+	});
+	assert_eq!(res.err(), Some([1, 1, 2]));
+	// A full 3x4 plane, a full row of 4, then 2 more cells
+	assert_eq!(built.get(), 12 + 4 + 2);
+	assert_eq!(drops.load(Ordering::SeqCst), built.get());
+}
+
+#[test]
+fn try_init_6d_array() {
+	let arr = <[[[[[[usize; 2]; 2]; 2]; 2]; 2]; 2] as TryInit<usize, [usize; 6]>>::try_init(|[u, v, w, x, y, z]| Ok::<_, ()>(u + v + w + x + y + z)).unwrap();
+	assert_eq!(arr[1][0][1][0][1][1], 4);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn try_init_vec() {
+	let vec = Vec::try_init_with(4, |i| Ok::<_, ()>(i + 1));
+	assert_eq!(vec, Ok(vec![1, 2, 3, 4]));
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn try_init_vec_error_drops_prefix() {
+	let drops = AtomicUsize::new(0);
+	let res = Vec::try_init_with(10, |i| if i == 3 { Err("failed") } else { Ok(Counted(&drops)) });
+	assert_eq!(res.err(), Some(InitError::Closure("failed")));
+	assert_eq!(drops.load(Ordering::SeqCst), 3);
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[test]
+fn try_init_vec_capacity_error() {
+	let res = Vec::<u32>::try_init_with(usize::MAX, |_| -> Result<u32, ()> { panic!("Shouldn't call init function") });
+	assert!(matches!(res, Err(InitError::Capacity(_))));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_error_source() {
+	use std::error::Error;
+	
+	let err = Vec::<u8>::try_init_with(2, |_| "x".parse::<u8>()).unwrap_err();
+	assert!(err.to_string().starts_with("initialising an element failed: "));
+	assert!(err.source().is_some());
+}
@@ -0,0 +1,7 @@
+use init_trait::init_const_permuted;
+
+const TABLE: [u8; 3] = init_const_permuted([1, 2, 3], [0, 2, 0]);
+
+fn main() {
+	let _ = TABLE;
+}
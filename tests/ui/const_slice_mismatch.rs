@@ -0,0 +1,8 @@
+use init_trait::init_from_const_slice;
+
+const TABLE: &[u8] = &[1, 2, 3];
+const ARR: [u8; 4] = init_from_const_slice(TABLE);
+
+fn main() {
+	let _ = ARR;
+}
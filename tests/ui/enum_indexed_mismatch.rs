@@ -0,0 +1,14 @@
+use strum::{EnumCount, EnumIter};
+
+use init_trait::init_enum_indexed;
+
+#[derive(Clone, Copy, EnumCount, EnumIter)]
+enum Axis {
+	X,
+	Y,
+	Z,
+}
+
+fn main() {
+	let _: [Axis; 2] = init_enum_indexed(|axis| axis);
+}
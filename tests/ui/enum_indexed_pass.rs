@@ -0,0 +1,18 @@
+use strum::{EnumCount, EnumIter};
+
+use init_trait::init_enum_indexed;
+
+#[derive(Clone, Copy, Debug, PartialEq, EnumCount, EnumIter)]
+enum Axis {
+	X,
+	Y,
+	Z,
+}
+
+fn main() {
+	let order: [Axis; Axis::COUNT] = init_enum_indexed(|axis| axis);
+	assert_eq!(order, [Axis::X, Axis::Y, Axis::Z]);
+	
+	let names = init_enum_indexed::<Axis, _, _, { Axis::COUNT }>(|axis| axis as u8);
+	assert_eq!(names, [0, 1, 2]);
+}
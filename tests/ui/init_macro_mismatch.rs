@@ -0,0 +1,6 @@
+use init_trait::init;
+
+fn main() {
+	// Two dimensions but only one index
+	let _ = init!([usize; 2][usize; 3] => |r| r);
+}
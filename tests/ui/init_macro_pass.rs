@@ -0,0 +1,11 @@
+use init_trait::init;
+
+const SIZE: usize = 3;
+
+fn main() {
+	let grid: [[u8; SIZE]; 2] = init!([usize; 2][usize; SIZE] => |r, c| (r * SIZE + c) as u8);
+	assert_eq!(grid, [[0, 1, 2], [3, 4, 5]]);
+	
+	let cube = init!([usize; 2][usize; 2][usize; 2] => |_, _, z| z);
+	assert_eq!(cube[1][1], [0, 1]);
+}
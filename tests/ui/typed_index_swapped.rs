@@ -0,0 +1,11 @@
+use init_trait::{GridInit, Row, Column};
+
+struct Lat(usize);
+struct Lon(usize);
+
+impl From<Row> for Lat { fn from(Row(r): Row) -> Self { Lat(r) } }
+impl From<Column> for Lon { fn from(Column(c): Column) -> Self { Lon(c) } }
+
+fn main() {
+	let _ = <[[usize; 3]; 2]>::init_typed(|(Lon(c), Lat(r))| r * 10 + c);
+}
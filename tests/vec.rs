@@ -0,0 +1,674 @@
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+mod common;
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use init_trait::{Init, VecInit, init_pair_with, init_jagged_with, init_encode, init_flat_map, init_normalised_with, init_concat, init_chunks, init_decode_records, init_zip_with, init_with_inverse, init_deinterleave, init_ragged, init_boxed_raw, reclaim};
+
+use common::{Counted, LogDrop};
+
+#[test]
+fn init_interleave() {
+	let vec = Vec::init_interleave_with(3, |i| ('L', i), |i| ('R', i));
+	assert_eq!(vec, vec![('L', 0), ('R', 0), ('L', 1), ('R', 1), ('L', 2), ('R', 2)]);
+}
+
+#[test]
+fn init_interleave_call_order() {
+	let calls = RefCell::new(Vec::new());
+	Vec::init_interleave_with(2, |i| calls.borrow_mut().push(('L', i)), |i| calls.borrow_mut().push(('R', i)));
+	assert_eq!(calls.into_inner(), vec![('L', 0), ('R', 0), ('L', 1), ('R', 1)]);
+}
+
+#[test]
+fn init_interleave_empty() {
+	let vec = Vec::<usize>::init_interleave_with(0, |_| panic!("Shouldn't call left"), |_| panic!("Shouldn't call right"));
+	assert!(vec.is_empty());
+}
+
+#[test]
+fn init_until_prefix_sum() {
+	let vec = Vec::init_until(|i, prev: &[usize]| {
+		if prev.iter().sum::<usize>() > 20 { None } else { Some(i + 1) }
+	});
+	// 1 + 2 + 3 + 4 + 5 + 6 = 21 > 20
+	assert_eq!(vec, vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn init_until_sees_prefix() {
+	let mut seen = Vec::new();
+	let vec = Vec::init_until(|i, prev: &[usize]| {
+		seen.push(prev.to_vec());
+		if i < 3 { Some(i * 10) } else { None }
+	});
+	assert_eq!(vec, vec![0, 10, 20]);
+	assert_eq!(seen, vec![vec![], vec![0], vec![0, 10], vec![0, 10, 20]]);
+}
+
+#[test]
+fn init_until_immediately() {
+	let vec = Vec::<usize>::init_until(|_, _| None);
+	assert!(vec.is_empty());
+}
+
+#[test]
+fn init_with_fold_sum() {
+	let (vec, sum) = Vec::init_with_fold(5, 0, |i| i + 1, |acc, &x| *acc += x);
+	assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+	assert_eq!(sum, 15);
+}
+
+#[test]
+fn init_with_fold_max() {
+	let (vec, max) = Vec::init_with_fold(5, None, |i| (i * 7) % 5, |acc: &mut Option<usize>, &x| *acc = (*acc).max(Some(x)));
+	assert_eq!(vec, vec![0, 2, 4, 1, 3]);
+	assert_eq!(max, Some(4));
+}
+
+#[test]
+fn init_with_fold_empty() {
+	let (vec, acc) = Vec::<usize>::init_with_fold(0, 7, |_| panic!("Shouldn't call init function"), |_, _| panic!("Shouldn't call fold function"));
+	assert!(vec.is_empty());
+	assert_eq!(acc, 7);
+}
+
+#[test]
+fn init_pair() {
+	let (numbers, letters) = init_pair_with(3, |i| (i, (b'a' + i as u8) as char));
+	assert_eq!(numbers, vec![0, 1, 2]);
+	assert_eq!(letters, vec!['a', 'b', 'c']);
+}
+
+#[test]
+fn init_pair_empty() {
+	let (a, b): (Vec<usize>, Vec<usize>) = init_pair_with(0, |_| panic!("Shouldn't call init function"));
+	assert!(a.is_empty() && b.is_empty());
+}
+
+#[test]
+fn init_jagged() {
+	let jagged = init_jagged_with(3, |r| r + 1, |_| 'x');
+	assert_eq!(jagged, vec![vec!['x'], vec!['x', 'x'], vec!['x', 'x', 'x']]);
+}
+
+#[test]
+fn init_jagged_indexes() {
+	let mut row_calls = Vec::new();
+	let jagged = init_jagged_with(4, |r| { row_calls.push(r); [2, 0, 1, 3][r] }, |[r, c]| (r, c));
+	assert_eq!(row_calls, vec![0, 1, 2, 3]);
+	assert_eq!(jagged, vec![vec![(0, 0), (0, 1)], vec![], vec![(2, 0)], vec![(3, 0), (3, 1), (3, 2)]]);
+}
+
+#[test]
+fn init_validated_monotonic() {
+	fn monotonic(v: &[usize]) -> Result<(), usize> {
+		match v.windows(2).position(|w| w[0] > w[1]) {
+			Some(i) => Err(i + 1),
+			None => Ok(()),
+		}
+	}
+	
+	assert_eq!(Vec::init_validated_with(4, |i| i * i, monotonic), Ok(vec![0, 1, 4, 9]));
+	assert_eq!(Vec::init_validated_with(5, |i| [1, 2, 3, 2, 5][i], monotonic), Err(3));
+}
+
+#[test]
+fn init_validated_drops_invalid() {
+	use std::rc::Rc;
+	
+	let shared = Rc::new(());
+	let res = Vec::init_validated_with(3, |_| Rc::clone(&shared), |v| if v.len() == 3 { Err(()) } else { Ok(()) });
+	assert_eq!(res, Err(()));
+	assert_eq!(Rc::strong_count(&shared), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_memoised_alternating() {
+	use std::rc::Rc;
+	use init_trait::init_memoised;
+	
+	let mut builds = Vec::new();
+	let shared = init_memoised(6, |i| i % 2 == 0, |&even| { builds.push(even); if even { "even" } else { "odd" } });
+	assert_eq!(builds, vec![true, false]);
+	assert_eq!(shared.iter().map(|s| **s).collect::<Vec<_>>(), vec!["even", "odd", "even", "odd", "even", "odd"]);
+	assert!(Rc::ptr_eq(&shared[1], &shared[5]));
+	assert_eq!(Rc::strong_count(&shared[0]), 3);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_memoised_empty() {
+	let shared = init_trait::init_memoised(0, |i| i, |_: &usize| -> u8 { panic!("Shouldn't call build function") });
+	assert!(shared.is_empty());
+}
+
+#[test]
+fn init_tracking_sentinel_reports_indices() {
+	let (vec, sentinels) = Vec::init_tracking_sentinel(6, |i| if i == 1 || i == 4 { None } else { Some(i) }, Option::is_none);
+	assert_eq!(vec, vec![Some(0), None, Some(2), Some(3), None, Some(5)]);
+	assert_eq!(sentinels, vec![1, 4]);
+}
+
+#[test]
+fn init_tracking_sentinel_none_matched() {
+	let (vec, sentinels) = Vec::init_tracking_sentinel(3, |i| i, |_| false);
+	assert_eq!(vec, vec![0, 1, 2]);
+	assert!(sentinels.is_empty());
+}
+
+#[test]
+fn init_encode_le_u16() {
+	let count = 5;
+	let bytes = init_encode(count, |i, buf| buf.extend_from_slice(&(i as u16 * 300).to_le_bytes()));
+	assert_eq!(bytes.len(), 2 * count);
+	assert_eq!(&bytes[2..4], &300u16.to_le_bytes());
+	assert_eq!(u16::from_le_bytes([bytes[8], bytes[9]]), 1200);
+}
+
+#[test]
+fn init_encode_variable_length() {
+	let bytes = init_encode(4, |i, buf| buf.resize(buf.len() + i, i as u8));
+	assert_eq!(bytes, vec![1, 2, 2, 3, 3, 3]);
+}
+
+#[test]
+fn init_chunks_flattened() {
+	let chunks: Vec<[usize; 4]> = init_chunks(3, |i| i);
+	assert_eq!(chunks.len(), 3);
+	assert_eq!(chunks.iter().flatten().copied().collect::<Vec<_>>(), (0..12).collect::<Vec<_>>());
+}
+
+#[test]
+fn init_chunks_zero_width() {
+	let mut calls = 0;
+	let chunks: Vec<[u8; 0]> = init_chunks(5, |_| { calls += 1; 0 });
+	assert_eq!(chunks.len(), 5);
+	assert_eq!(calls, 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn threaded_init_matches_sequential() {
+	assert_eq!(Vec::threaded_init_with(3, 1000, |i| i ^ 0x55), (0..1000).map(|i| i ^ 0x55).collect::<Vec<_>>());
+	assert_eq!(Vec::threaded_init_with(0, 3, |i| i), vec![0, 1, 2]);
+	assert!(Vec::<u8>::threaded_init_with(8, 0, |_| panic!("Shouldn't call init function")).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn threaded_init_panic_propagates() {
+	use std::panic::catch_unwind;
+	
+	let built = AtomicUsize::new(0);
+	let drops = AtomicUsize::new(0);
+	let res = catch_unwind(|| {
+		Vec::threaded_init_with(4, 40, |i| {
+			if i == 25 { panic!("Stripe failed"); }
+			built.fetch_add(1, Ordering::SeqCst);
+			Counted(&drops)
+		})
+	});
+	let payload = res.err().expect("panic should propagate");
+	assert_eq!(payload.downcast_ref::<&str>(), Some(&"Stripe failed"));
+	assert_eq!(built.load(Ordering::SeqCst), 40 - 5);
+	assert_eq!(drops.load(Ordering::SeqCst), built.load(Ordering::SeqCst));
+}
+
+#[test]
+fn init_within_bytes_rounds_down() {
+	let vec = Vec::<u64>::init_within_bytes(63, |i| i as u64);
+	assert_eq!(vec, (0..7).collect::<Vec<u64>>());
+	assert!(Vec::<u64>::init_within_bytes(7, |_| panic!("Shouldn't call init function")).is_empty());
+}
+
+#[test]
+fn init_within_bytes_zst() {
+	let mut calls = 0;
+	let vec = Vec::<()>::init_within_bytes(1024, |_| calls += 1);
+	assert!(vec.is_empty());
+	assert_eq!(calls, 0);
+}
+
+#[test]
+fn init_with_retry_transient() {
+	let mut failures = 0;
+	let mut retries = Vec::new();
+	let vec = Vec::init_with_retry(4, |i| {
+		if i == 2 && failures < 2 { failures += 1; Err("transient") } else { Ok(i * 10) }
+	}, |_, attempts| { retries.push(attempts); attempts < 3 });
+	assert_eq!(vec, Ok(vec![0, 10, 20, 30]));
+	assert_eq!(retries, vec![1, 2]);
+}
+
+#[test]
+fn init_with_retry_gives_up() {
+	let mut calls = 0;
+	let vec = Vec::<usize>::init_with_retry(4, |i| { calls += 1; if i == 1 { Err(i) } else { Ok(i) } }, |_, attempts| attempts < 3);
+	assert_eq!(vec, Err(1));
+	assert_eq!(calls, 1 + 3);
+}
+
+#[test]
+fn init_with_inverse_roundtrip() {
+	let (forward, inverse) = init_with_inverse(10, |i| (i * 3) % 10);
+	assert_eq!(forward, vec![0, 3, 6, 9, 2, 5, 8, 1, 4, 7]);
+	for i in 0..10 {
+		assert_eq!(inverse[forward[i]], i);
+		assert_eq!(forward[inverse[i]], i);
+	}
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "already used")]
+fn init_with_inverse_not_bijective() {
+	init_with_inverse(4, |i| i / 2);
+}
+
+#[test]
+fn init_deinterleave_roundtrip() {
+	let stereo = Vec::init_interleave_with(4, |i| (i as i32) + 1, |i| -(i as i32) - 1);
+	assert_eq!(init_deinterleave(&stereo, 2), vec![vec![1, 2, 3, 4], vec![-1, -2, -3, -4]]);
+}
+
+#[test]
+fn init_deinterleave_uneven() {
+	let rgb = [1, 2, 3, 4, 5, 6, 7];
+	assert_eq!(init_deinterleave(&rgb, 3), vec![vec![1, 4, 7], vec![2, 5], vec![3, 6]]);
+	assert_eq!(init_deinterleave::<u8>(&[], 2), vec![Vec::<u8>::new(), Vec::new()]);
+}
+
+#[test]
+#[should_panic(expected = "0 channels")]
+fn init_deinterleave_zero_channels() {
+	init_deinterleave(&[1, 2, 3], 0);
+}
+
+#[test]
+fn init_with_progress_monotonic() {
+	let mut reports = Vec::new();
+	let vec = Vec::init_with_progress(1050, |i| i, |done, total| reports.push((done, total)));
+	assert_eq!(vec.len(), 1050);
+	assert!(reports.windows(2).all(|w| w[0].0 < w[1].0));
+	assert!(reports.iter().all(|&(_, total)| total == 1050));
+	assert_eq!(reports.last(), Some(&(1050, 1050)));
+	assert_eq!(reports[0], (10, 1050));
+	assert_eq!(reports.len(), 105);
+}
+
+#[test]
+fn init_with_progress_empty() {
+	let mut reports = Vec::new();
+	let vec = Vec::<u8>::init_with_progress(0, |_| panic!("Shouldn't call init function"), |done, total| reports.push((done, total)));
+	assert!(vec.is_empty());
+	assert_eq!(reports, vec![(0, 0)]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_with_index_first_occurrences() {
+	let (vec, index) = init_trait::init_with_index(8, |i| (i * i) % 5);
+	assert_eq!(vec, vec![0, 1, 4, 4, 1, 0, 1, 4]);
+	assert_eq!(index.len(), 3);
+	assert_eq!(index[&0], 0);
+	assert_eq!(index[&1], 1);
+	assert_eq!(index[&4], 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_with_index_distinct() {
+	let (vec, index) = init_trait::init_with_index(3, |i| i.to_string());
+	assert_eq!(index.len(), vec.len());
+	assert!(vec.iter().enumerate().all(|(i, x)| index[x] == i));
+}
+
+#[test]
+fn init_ragged_row_major() {
+	let flat = init_ragged(&[2, 3], |coords| (coords[0], coords[1]));
+	assert_eq!(flat, vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+}
+
+#[test]
+fn init_ragged_matches_fixed_rank() {
+	let flat = init_ragged(&[3, 2, 4], |c| c[0] * 100 + c[1] * 10 + c[2]);
+	let mut expected = Vec::new();
+	for x in 0..3 { for y in 0..2 { for z in 0..4 { expected.push(x * 100 + y * 10 + z); } } }
+	assert_eq!(flat, expected);
+}
+
+#[test]
+fn init_ragged_degenerate_shapes() {
+	assert_eq!(init_ragged(&[], |c| c.len()), vec![0]);
+	assert!(init_ragged(&[4, 0, 2], |_| -> u8 { panic!("Shouldn't call init function") }).is_empty());
+}
+
+#[test]
+fn init_cancellable_prefix_dropped() {
+	use std::sync::atomic::AtomicBool;
+	use init_trait::Cancelled;
+	
+	let drops = AtomicUsize::new(0);
+	let cancel = AtomicBool::new(false);
+	let res = Vec::init_cancellable_with(10, &cancel, |i| {
+		if i == 2 { cancel.store(true, Ordering::Release); }
+		Counted(&drops)
+	});
+	assert!(matches!(res, Err(Cancelled)));
+	assert_eq!(drops.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn init_cancellable_error_display() {
+	assert_eq!(init_trait::Cancelled.to_string(), "initialisation was cancelled");
+}
+
+#[test]
+fn init_boxed_raw_roundtrip() {
+	use std::rc::Rc;
+	
+	let tracker = Rc::new(());
+	let (ptr, len) = init_boxed_raw(4, |_| Rc::clone(&tracker));
+	assert_eq!(len, 4);
+	assert_eq!(Rc::strong_count(&tracker), 5);
+	
+	// SAFETY: `ptr` and `len` came from `init_boxed_raw` and are reclaimed only once
+	let slice = unsafe { reclaim(ptr, len) };
+	assert_eq!(slice.len(), 4);
+	drop(slice);
+	assert_eq!(Rc::strong_count(&tracker), 1);
+}
+
+#[test]
+fn init_boxed_raw_modified_in_place() {
+	let (ptr, len) = init_boxed_raw(3, |i| i * 10);
+	// SAFETY: `ptr` is valid for `len` elements until it's reclaimed, and is reclaimed only once
+	let slice = unsafe {
+		*ptr.add(1) = 99;
+		reclaim(ptr, len)
+	};
+	assert_eq!(*slice, [0, 99, 20]);
+}
+
+#[test]
+fn init_boxed_raw_empty() {
+	let (ptr, len) = init_boxed_raw(0, |_| -> String { panic!("Shouldn't call init function") });
+	assert!(!ptr.is_null());
+	// SAFETY: `ptr` and `len` came from `init_boxed_raw` and are reclaimed only once
+	assert!(unsafe { reclaim(ptr, len) }.is_empty());
+}
+
+#[test]
+fn init_dedup_consecutive_runs() {
+	let src = [1, 1, 2, 2, 1];
+	assert_eq!(Vec::init_dedup_consecutive_with(src.len(), |i| src[i]), vec![1, 2, 1]);
+}
+
+#[test]
+fn init_dedup_consecutive_calls_every_index() {
+	let mut calls = 0;
+	let vec = Vec::init_dedup_consecutive_with(5, |_| { calls += 1; 'x' });
+	assert_eq!(vec, vec!['x']);
+	assert_eq!(calls, 5);
+	assert!(Vec::<u8>::init_dedup_consecutive_with(0, |_| panic!("Shouldn't call init function")).is_empty());
+}
+
+#[test]
+fn init_try_from_iter_all_ok() {
+	let vec = Vec::init_try_from_iter((0..5).map(Ok::<_, ()>));
+	assert_eq!(vec, Ok(vec![0, 1, 2, 3, 4]));
+	assert!(vec.unwrap().capacity() >= 5);
+}
+
+#[test]
+fn init_try_from_iter_early_err() {
+	let drops = AtomicUsize::new(0);
+	let mut pulled = 0;
+	let res = Vec::init_try_from_iter((0..10).map(|i| { pulled += 1; if i == 3 { Err(i) } else { Ok(Counted(&drops)) } }));
+	assert!(matches!(res, Err(3)));
+	assert_eq!(drops.load(Ordering::SeqCst), 3);
+	assert_eq!(pulled, 4);
+}
+
+#[test]
+fn init_flat_map_repeats() {
+	let expanded = init_flat_map(5, |i| vec![i; i]);
+	assert_eq!(expanded, vec![1, 2, 2, 3, 3, 3, 4, 4, 4, 4]);
+}
+
+#[test]
+fn init_flat_map_empty() {
+	let expanded: Vec<usize> = init_flat_map(4, |_| None);
+	assert!(expanded.is_empty());
+	assert!(init_flat_map(0, Some).is_empty());
+}
+
+#[test]
+fn init_normalised_sums_to_one() {
+	let probs = init_normalised_with(7, |i| (i * i + 1) as f64);
+	assert_eq!(probs.len(), 7);
+	assert!((probs.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+	assert!(probs.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn init_normalised_zero_sum() {
+	assert_eq!(init_normalised_with(3, |_| 0.0), vec![0.0; 3]);
+	assert!(init_normalised_with(0, |_| 1.0).is_empty());
+}
+
+#[test]
+fn init_concat_local_indices() {
+	let first = |i| i * 10;
+	let second = |i| 100 + i;
+	let value = init_concat(&[(2, &first), (3, &second)]);
+	assert_eq!(value, vec![0, 10, 100, 101, 102]);
+}
+
+#[test]
+fn init_concat_empty_segments() {
+	let elem = |i| i;
+	assert_eq!(init_concat(&[(0, &elem), (2, &elem), (0, &elem)]), vec![0, 1]);
+	assert!(init_concat::<usize>(&[]).is_empty());
+}
+
+#[test]
+fn init_vec_push_threshold_boundary() {
+	for length in 0..=32 {
+		let mut expected = Vec::new();
+		for i in 0..length {
+			expected.push(i * 3 + 1);
+		}
+		assert_eq!(Vec::init_with(length, |i| i * 3 + 1), expected, "length {}", length);
+	}
+}
+
+#[test]
+fn init_vec_short_panic_drops_in_reverse() {
+	use std::panic::{catch_unwind, AssertUnwindSafe};
+	
+	let log = RefCell::new(Vec::new());
+	let res = catch_unwind(AssertUnwindSafe(|| {
+		Vec::init_with(7, |i| {
+			if i == 3 { panic!("Init function failed"); }
+			LogDrop(i, &log)
+		})
+	}));
+	assert!(res.is_err());
+	assert_eq!(*log.borrow(), vec![2, 1, 0]);
+}
+
+#[test]
+fn init_with_retry_short_error_drops_in_reverse() {
+	let log = RefCell::new(Vec::new());
+	let res = Vec::init_with_retry(5, |i| if i < 3 { Ok(LogDrop(i, &log)) } else { Err(i) }, |_, _| false);
+	assert_eq!(res.err(), Some(3));
+	assert_eq!(*log.borrow(), vec![2, 1, 0]);
+}
+
+#[test]
+fn init_cancellable_short_drops_in_reverse() {
+	use std::sync::atomic::AtomicBool;
+	
+	let log = RefCell::new(Vec::new());
+	let cancel = AtomicBool::new(false);
+	let res = Vec::init_cancellable_with(6, &cancel, |i| {
+		if i == 2 { cancel.store(true, Ordering::Release); }
+		LogDrop(i, &log)
+	});
+	assert!(res.is_err());
+	assert_eq!(*log.borrow(), vec![2, 1, 0]);
+}
+
+#[test]
+fn try_init_vec_push_threshold_drops() {
+	// Either side of the threshold, an error drops the elements built so far in reverse
+	for length in 1..=32 {
+		let log = RefCell::new(Vec::new());
+		let res = Vec::init_with_retry(length, |i| if i + 1 < length { Ok(LogDrop(i, &log)) } else { Err(i) }, |_, _| false);
+		assert_eq!(res.err(), Some(length - 1), "length {}", length);
+		assert_eq!(*log.borrow(), (0..length - 1).rev().collect::<Vec<_>>(), "length {}", length);
+	}
+}
+
+#[test]
+fn init_lookback_two_tap_filter() {
+	let input = [1.0, 0.0, 0.0, 0.0, 2.0];
+	let output = Vec::init_lookback_with(input.len(), [0.0, 0.0], |i, &[y2, y1]| input[i] + 0.5 * y1 + 0.25 * y2);
+	assert_eq!(output, vec![1.0, 0.5, 0.5, 0.375, 2.3125]);
+}
+
+#[test]
+fn init_lookback_window_contents() {
+	let mut windows = Vec::new();
+	let value = Vec::init_lookback_with(4, [-2, -1, 0], |i, w| { windows.push(*w); i as i32 + 1 });
+	assert_eq!(value, vec![1, 2, 3, 4]);
+	assert_eq!(windows, vec![[-2, -1, 0], [-1, 0, 1], [0, 1, 2], [1, 2, 3]]);
+	
+	assert_eq!(Vec::init_lookback_with(3, [], |i, _| i), vec![0, 1, 2]);
+}
+
+#[test]
+fn init_budget_last_fitting_included() {
+	let weights = [3, 4, 2, 5, 1];
+	let mut calls = 0;
+	let packed = Vec::init_budget_with(9, |i| { calls += 1; (i, weights[i]) });
+	assert_eq!(packed, vec![0, 1, 2]);
+	assert_eq!(calls, 4);
+}
+
+#[test]
+fn init_budget_edges() {
+	assert!(Vec::init_budget_with(0, |i| (i, 1)).is_empty());
+	assert_eq!(Vec::init_budget_with(u64::MAX, |i| (i, if i < 2 { u64::MAX / 2 } else { u64::MAX })), vec![0, 1]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_cached_warm_cache() {
+	use std::collections::HashMap;
+	
+	let mut cache = HashMap::new();
+	let mut calls = 0;
+	let cold = Vec::init_cached_with(5, &mut cache, |i| { calls += 1; i * i });
+	assert_eq!(cold, vec![0, 1, 4, 9, 16]);
+	assert_eq!(calls, 5);
+	
+	let mut calls = 0;
+	let warm = Vec::init_cached_with(5, &mut cache, |i| { calls += 1; i * i });
+	assert_eq!(warm, cold);
+	assert_eq!(calls, 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn init_cached_partial_cache() {
+	use std::collections::HashMap;
+	
+	let mut cache: HashMap<usize, String> = (0..3).map(|i| (i, format!("cached {}", i))).collect();
+	let mut computed = Vec::new();
+	let value = Vec::init_cached_with(5, &mut cache, |i| { computed.push(i); format!("new {}", i) });
+	assert_eq!(value, vec!["cached 0", "cached 1", "cached 2", "new 3", "new 4"]);
+	assert_eq!(computed, vec![3, 4]);
+	assert_eq!(cache.len(), 5);
+}
+
+#[test]
+fn init_decode_records_u32() {
+	let bytes = [1, 0, 0, 0, 0, 1, 0, 0, 0xff, 0xff, 0xff, 0xff];
+	let values = init_decode_records(&bytes, |&rec| u32::from_le_bytes(rec));
+	assert_eq!(values, vec![1, 256, u32::MAX]);
+	
+	assert!(init_decode_records(&[], |&rec: &[u8; 4]| u32::from_le_bytes(rec)).is_empty());
+}
+
+#[test]
+fn init_decode_records_partial() {
+	let mut calls = 0;
+	let values = init_decode_records(&[1, 0, 0, 0, 2, 0], |&rec| { calls += 1; u32::from_le_bytes(rec) });
+	assert_eq!(values, vec![1]);
+	assert_eq!(calls, 1);
+	
+	assert!(init_decode_records(&[9; 3], |&rec: &[u8; 4]| u32::from_le_bytes(rec)).is_empty());
+}
+
+#[test]
+fn init_zip_with_labels() {
+	let value = init_zip_with(vec!['a', 'b', 'c'].into_iter(), |i, c| format!("{}{}", i, c));
+	assert_eq!(value, vec!["0a", "1b", "2c"]);
+	assert!(value.capacity() >= 3);
+}
+
+#[test]
+fn init_zip_with_empty() {
+	let value = init_zip_with(std::iter::empty::<u8>(), |i, d| (i, d));
+	assert!(value.is_empty());
+}
+
+#[test]
+fn init_centered_indices() {
+	let mut seen = Vec::new();
+	let value = Vec::init_centered_with(2, |i| { seen.push(i); i * 10 });
+	assert_eq!(value, vec![-20, -10, 0, 10, 20]);
+	assert_eq!(seen, vec![-2, -1, 0, 1, 2]);
+}
+
+#[test]
+fn init_centered_zero_width() {
+	assert_eq!(Vec::init_centered_with(0, |i| i), vec![0]);
+}
+
+#[test]
+#[should_panic(expected = "too large")]
+fn init_centered_too_wide() {
+	Vec::init_centered_with(usize::MAX / 2, |i| i);
+}
+
+#[test]
+fn init_shuffled_same_seed() {
+	let a = Vec::init_shuffled_with(50, 7, |i| i);
+	let b = Vec::init_shuffled_with(50, 7, |i| i);
+	assert_eq!(a, b);
+	
+	let mut sorted = a.clone();
+	sorted.sort_unstable();
+	assert_eq!(sorted, (0..50).collect::<Vec<_>>());
+	assert_ne!(a, sorted);
+}
+
+#[test]
+fn init_shuffled_different_seeds() {
+	let orders: Vec<Vec<usize>> = (0..8).map(|seed| Vec::init_shuffled_with(20, seed, |i| i)).collect();
+	for i in 0..orders.len() {
+		for j in i + 1..orders.len() {
+			assert_ne!(orders[i], orders[j], "seeds {} and {}", i, j);
+		}
+	}
+	
+	assert!(Vec::init_shuffled_with(0, 1, |i| i).is_empty());
+	assert_eq!(Vec::init_shuffled_with(1, 1, |i| i), vec![0]);
+}